@@ -0,0 +1,19 @@
+// --- Plugin de exemplu ---
+//
+// Se compileaza ca biblioteca dinamica (`cargo build --example upper_plugin`), apoi
+// se copiaza in `plugins/`, de unde terminalul il incarca la pornire:
+//
+//     cp target/debug/examples/libupper_plugin.so plugins/
+
+laborator_traits::export_plugin!(
+    "upper",
+    "Print the arguments, or the piped lines, in upper case",
+    |args: &[String], input: &[String]| {
+        let lines: Vec<String> = match (args, input) {
+            ([], []) => return Err("nothing to convert".to_string()),
+            ([], lines) => lines.iter().map(|line| line.to_uppercase()).collect(),
+            (words, _) => vec![words.join(" ").to_uppercase()],
+        };
+        Ok(lines)
+    }
+);
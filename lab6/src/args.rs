@@ -0,0 +1,501 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::suggest::{quote_list, suggestions};
+
+// --- Specificatia argumentelor unei comenzi ---
+//
+// Fiecare comanda descrie ce accepta printr-o `Signature` (construita in stil
+// builder). `Terminal` valideaza argumentele inainte de `exec` si, la eroare,
+// afiseaza mesajul si `Usage:` generat din aceeasi specificatie.
+
+/// Tipul unei valori; verificat la parsare, inainte de `exec`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArgKind {
+    Text,
+    Integer,
+    Url,
+}
+
+impl ArgKind {
+    fn check(self, value: &str) -> bool {
+        match self {
+            ArgKind::Text => true,
+            ArgKind::Integer => value.parse::<i64>().is_ok(),
+            ArgKind::Url => is_url(value),
+        }
+    }
+}
+
+impl Display for ArgKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgKind::Text => write!(f, "text"),
+            ArgKind::Integer => write!(f, "an integer"),
+            ArgKind::Url => write!(f, "a URL (scheme://host/...)"),
+        }
+    }
+}
+
+/// `scheme://host...`, cu schema din litere/cifre/`+.-` si host nevid
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+    let scheme_ok = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c));
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    scheme_ok && !host.is_empty() && !value.contains(char::is_whitespace)
+}
+
+#[derive(Debug, Clone)]
+struct Param {
+    name: &'static str,
+    kind: ArgKind,
+}
+
+#[derive(Debug, Clone)]
+struct Flag {
+    long: &'static str,
+    short: Option<char>,
+    /// `None` pentru un flag boolean, `Some(tip)` pentru o optiune cu valoare
+    value: Option<ArgKind>,
+}
+
+/// Ce argumente accepta o comanda: pozitionale (obligatorii, optionale, restul),
+/// flag-uri `--nume`/`-n`, optiuni `--nume <valoare>` si subcomenzi (un singur nivel).
+#[derive(Debug, Clone, Default)]
+pub struct Signature {
+    required: Vec<Param>,
+    optional: Vec<Param>,
+    rest: Option<Param>,
+    flags: Vec<Flag>,
+    subcommands: Vec<(&'static str, Signature)>,
+    /// Descrierea scurta afisata de `help` (folosita mai ales pentru subcomenzi)
+    about: &'static str,
+}
+
+impl Signature {
+    /// Nu accepta niciun argument
+    pub fn new() -> Self {
+        Signature::default()
+    }
+
+    /// Accepta orice lista de cuvinte (comportamentul comenzilor fara specificatie)
+    pub fn any() -> Self {
+        Signature::new().rest("args", ArgKind::Text)
+    }
+
+    pub fn required(mut self, name: &'static str, kind: ArgKind) -> Self {
+        self.required.push(Param { name, kind });
+        self
+    }
+
+    /// Pozitional optional; vine dupa toate cele obligatorii
+    pub fn optional(mut self, name: &'static str, kind: ArgKind) -> Self {
+        self.optional.push(Param { name, kind });
+        self
+    }
+
+    /// Toate pozitionalele ramase (zero sau mai multe)
+    pub fn rest(mut self, name: &'static str, kind: ArgKind) -> Self {
+        self.rest = Some(Param { name, kind });
+        self
+    }
+
+    pub fn flag(mut self, long: &'static str, short: Option<char>) -> Self {
+        self.flags.push(Flag {
+            long,
+            short,
+            value: None,
+        });
+        self
+    }
+
+    /// Optiune cu valoare: `--long <v>`, `--long=<v>` sau `-s <v>`
+    pub fn option(mut self, long: &'static str, short: Option<char>, kind: ArgKind) -> Self {
+        self.flags.push(Flag {
+            long,
+            short,
+            value: Some(kind),
+        });
+        self
+    }
+
+    pub fn subcommand(mut self, name: &'static str, signature: Signature) -> Self {
+        self.subcommands.push((name, signature));
+        self
+    }
+
+    pub fn about(mut self, description: &'static str) -> Self {
+        self.about = description;
+        self
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.about
+    }
+
+    pub fn subcommand_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.subcommands.iter().map(|(name, _)| *name)
+    }
+
+    pub fn subcommands(&self) -> impl Iterator<Item = (&'static str, &Signature)> + '_ {
+        self.subcommands.iter().map(|(name, signature)| (*name, signature))
+    }
+
+    pub fn find_subcommand(&self, name: &str) -> Option<&Signature> {
+        self.subcommands
+            .iter()
+            .find(|(sub, _)| *sub == name)
+            .map(|(_, signature)| signature)
+    }
+
+    /// Valideaza argumentele brute si le intoarce pe nume
+    pub fn parse(&self, args: &[String]) -> Result<Args, ArgError> {
+        if !self.subcommands.is_empty() {
+            let expected = self.subcommand_names().collect();
+            let Some(first) = args.first() else {
+                return Err(ArgError::MissingSubcommand { expected });
+            };
+            let Some((name, signature)) = self.subcommands.iter().find(|(name, _)| name == first) else {
+                let suggestions = suggestions(first, self.subcommand_names());
+                return Err(ArgError::UnknownSubcommand { given: first.clone(), expected, suggestions });
+            };
+            let mut parsed = signature.parse(&args[1..])?;
+            parsed.subcommand = Some(name);
+            return Ok(parsed);
+        }
+
+        let mut parsed = Args::default();
+        let mut positionals = Vec::new();
+        let mut only_positionals = self.flags.is_empty();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if only_positionals || !looks_like_flag(arg) {
+                positionals.push(arg);
+                continue;
+            }
+            if arg == "--" {
+                only_positionals = true;
+                continue;
+            }
+
+            let (flag, inline) = self.find_flag(arg)?;
+            match (flag.value, inline) {
+                (None, None) => {
+                    parsed.flags.insert(flag.long);
+                }
+                (None, Some(_)) => return Err(ArgError::FlagTakesNoValue(flag.long)),
+                (Some(kind), inline) => {
+                    let value = match inline {
+                        Some(value) => value.to_string(),
+                        None => iter.next().ok_or(ArgError::MissingFlagValue(flag.long))?.clone(),
+                    };
+                    check(flag.long, kind, &value)?;
+                    parsed.values.insert(flag.long, value);
+                }
+            }
+        }
+
+        let mut positionals = positionals.into_iter();
+        for param in &self.required {
+            let value = positionals.next().ok_or(ArgError::MissingArgument(param.name))?;
+            check(param.name, param.kind, value)?;
+            parsed.values.insert(param.name, value.clone());
+        }
+        for param in &self.optional {
+            if let Some(value) = positionals.next() {
+                check(param.name, param.kind, value)?;
+                parsed.values.insert(param.name, value.clone());
+            }
+        }
+        for value in positionals {
+            let Some(param) = &self.rest else {
+                return Err(ArgError::UnexpectedArgument(value.clone()));
+            };
+            check(param.name, param.kind, value)?;
+            parsed.rest.push(value.clone());
+        }
+        Ok(parsed)
+    }
+
+    fn find_flag<'a>(&self, arg: &'a str) -> Result<(&Flag, Option<&'a str>), ArgError> {
+        let found = if let Some(long) = arg.strip_prefix("--") {
+            let (name, inline) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+            self.flags.iter().find(|f| f.long == name).map(|f| (f, inline))
+        } else {
+            let short = arg[1..].chars().next();
+            self.flags
+                .iter()
+                .find(|f| arg.len() == 2 && f.short == short)
+                .map(|f| (f, None))
+        };
+        found.ok_or_else(|| ArgError::UnknownFlag(arg.to_string()))
+    }
+
+    /// Linia de utilizare, ex: `bk add <name> <url> OR bk search <name> [--limit <n>]`
+    pub fn usage(&self, name: &str) -> String {
+        self.usage_lines(name).join(" OR ")
+    }
+
+    /// Cate o linie de utilizare pentru fiecare subcomanda (sau una singura)
+    pub fn usage_lines(&self, name: &str) -> Vec<String> {
+        if !self.subcommands.is_empty() {
+            return self
+                .subcommands
+                .iter()
+                .flat_map(|(sub, signature)| signature.usage_lines(&format!("{} {}", name, sub)))
+                .collect();
+        }
+
+        let mut usage = name.to_string();
+        for param in &self.required {
+            usage += &format!(" <{}>", param.name);
+        }
+        for param in &self.optional {
+            usage += &format!(" [{}]", param.name);
+        }
+        if let Some(param) = &self.rest {
+            usage += &format!(" [{}...]", param.name);
+        }
+        for flag in &self.flags {
+            let value = if flag.value.is_some() {
+                format!(" <{}>", flag.long)
+            } else {
+                String::new()
+            };
+            match flag.short {
+                Some(short) => usage += &format!(" [-{}|--{}{}]", short, flag.long, value),
+                None => usage += &format!(" [--{}{}]", flag.long, value),
+            }
+        }
+        vec![usage]
+    }
+
+    /// Ca `usage`, dar pentru subcomanda aleasa in `args` (daca exista)
+    pub fn usage_for(&self, name: &str, args: &[String]) -> String {
+        let chosen = args
+            .first()
+            .and_then(|first| self.subcommands.iter().find(|(sub, _)| sub == first));
+        match chosen {
+            Some((sub, signature)) => signature.usage(&format!("{} {}", name, sub)),
+            None => self.usage(name),
+        }
+    }
+}
+
+/// `-x`, `--xyz` sau `--`, dar nu un numar negativ ca `-5`
+fn looks_like_flag(arg: &str) -> bool {
+    let mut chars = arg.chars();
+    chars.next() == Some('-') && chars.next().is_some_and(|c| !c.is_ascii_digit())
+}
+
+fn check(name: &'static str, kind: ArgKind, value: &str) -> Result<(), ArgError> {
+    if kind.check(value) {
+        Ok(())
+    } else {
+        Err(ArgError::InvalidValue {
+            name,
+            value: value.to_string(),
+            kind,
+        })
+    }
+}
+
+// --- Argumentele validate ---
+
+/// Rezultatul lui `Signature::parse`; valorile au fost deja verificate dupa tip.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Args {
+    subcommand: Option<&'static str>,
+    values: HashMap<&'static str, String>,
+    flags: HashSet<&'static str>,
+    rest: Vec<String>,
+}
+
+impl Args {
+    pub fn subcommand(&self) -> Option<&'static str> {
+        self.subcommand
+    }
+
+    /// Valoarea unui pozitional sau a unei optiuni, daca a fost data
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Valoarea unui parametru de tip `ArgKind::Integer`
+    pub fn get_int(&self, name: &str) -> Option<i64> {
+        self.get_str(name).and_then(|value| value.parse().ok())
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    /// Pozitionalele prinse de `Signature::rest`
+    pub fn rest(&self) -> &[String] {
+        &self.rest
+    }
+}
+
+// --- Erori de validare ---
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgError {
+    MissingArgument(&'static str),
+    UnexpectedArgument(String),
+    InvalidValue {
+        name: &'static str,
+        value: String,
+        kind: ArgKind,
+    },
+    UnknownFlag(String),
+    MissingFlagValue(&'static str),
+    FlagTakesNoValue(&'static str),
+    MissingSubcommand {
+        expected: Vec<&'static str>,
+    },
+    UnknownSubcommand {
+        given: String,
+        expected: Vec<&'static str>,
+        /// Subcomenzile apropiate de `given` (vezi `suggest`)
+        suggestions: Vec<&'static str>,
+    },
+}
+
+impl Display for ArgError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgError::MissingArgument(name) => write!(f, "missing argument <{}>", name),
+            ArgError::UnexpectedArgument(value) => write!(f, "unexpected argument '{}'", value),
+            ArgError::InvalidValue { name, value, kind } => {
+                write!(f, "invalid value '{}' for <{}>: expected {}", value, name, kind)
+            }
+            ArgError::UnknownFlag(flag) => write!(f, "unknown flag '{}'", flag),
+            ArgError::MissingFlagValue(flag) => write!(f, "flag '--{}' requires a value", flag),
+            ArgError::FlagTakesNoValue(flag) => write!(f, "flag '--{}' does not take a value", flag),
+            ArgError::MissingSubcommand { expected } => {
+                write!(f, "missing subcommand, use {}", quote_list(expected))
+            }
+            ArgError::UnknownSubcommand { given, expected, suggestions } => {
+                write!(f, "unknown subcommand '{}', use {}", given, quote_list(expected))?;
+                if !suggestions.is_empty() {
+                    write!(f, " (did you mean {}?)", quote_list(suggestions))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ArgError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    fn bk() -> Signature {
+        Signature::new()
+            .subcommand(
+                "add",
+                Signature::new()
+                    .required("name", ArgKind::Text)
+                    .required("url", ArgKind::Url),
+            )
+            .subcommand(
+                "search",
+                Signature::new()
+                    .required("name", ArgKind::Text)
+                    .option("limit", Some('n'), ArgKind::Integer),
+            )
+    }
+
+    #[test]
+    fn positionals_and_rest() {
+        let sig = Signature::new()
+            .required("a", ArgKind::Integer)
+            .optional("b", ArgKind::Text)
+            .rest("more", ArgKind::Text);
+        let args = sig.parse(&words("-3 x y z")).unwrap();
+        assert_eq!(args.get_int("a"), Some(-3));
+        assert_eq!(args.get_str("b"), Some("x"));
+        assert_eq!(args.rest(), ["y", "z"]);
+
+        assert_eq!(sig.parse(&[]), Err(ArgError::MissingArgument("a")));
+        assert!(matches!(
+            sig.parse(&words("x")),
+            Err(ArgError::InvalidValue { name: "a", .. })
+        ));
+        assert_eq!(
+            Signature::new().parse(&words("x")),
+            Err(ArgError::UnexpectedArgument("x".into()))
+        );
+        assert_eq!(sig.usage("cmd"), "cmd <a> [b] [more...]");
+    }
+
+    #[test]
+    fn flags_and_options() {
+        let sig = Signature::new()
+            .flag("verbose", Some('v'))
+            .option("limit", None, ArgKind::Integer)
+            .rest("w", ArgKind::Text);
+        let args = sig.parse(&words("a -v --limit=3 b -- --verbose")).unwrap();
+        assert!(args.flag("verbose"));
+        assert_eq!(args.get_int("limit"), Some(3));
+        assert_eq!(args.rest(), ["a", "b", "--verbose"]);
+
+        assert_eq!(sig.parse(&words("--limit")), Err(ArgError::MissingFlagValue("limit")));
+        assert_eq!(
+            sig.parse(&words("--verbose=1")),
+            Err(ArgError::FlagTakesNoValue("verbose"))
+        );
+        assert_eq!(sig.parse(&words("-x")), Err(ArgError::UnknownFlag("-x".into())));
+        // fara flag-uri declarate, `-x` este un cuvant oarecare
+        assert_eq!(Signature::any().parse(&words("-x")).unwrap().rest(), ["-x"]);
+    }
+
+    #[test]
+    fn subcommands_and_usage() {
+        let args = bk().parse(&words("search rust -n 2")).unwrap();
+        assert_eq!(args.subcommand(), Some("search"));
+        assert_eq!(args.get_str("name"), Some("rust"));
+        assert_eq!(args.get_int("limit"), Some(2));
+
+        let err = bk().parse(&words("add rust rust-lang.org")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value 'rust-lang.org' for <url>: expected a URL (scheme://host/...)"
+        );
+        assert_eq!(
+            bk().parse(&words("del x")).unwrap_err().to_string(),
+            "unknown subcommand 'del', use 'add' or 'search'"
+        );
+        assert_eq!(
+            bk().parse(&words("serch x")).unwrap_err().to_string(),
+            "unknown subcommand 'serch', use 'add' or 'search' (did you mean 'search'?)"
+        );
+        assert_eq!(
+            bk().usage("bk"),
+            "bk add <name> <url> OR bk search <name> [-n|--limit <limit>]"
+        );
+        assert_eq!(bk().usage_for("bk", &words("add x")), "bk add <name> <url>");
+    }
+
+    #[test]
+    fn url_check() {
+        assert!(is_url("https://rust-lang.org"));
+        assert!(is_url("file://host/path?q#f"));
+        assert!(!is_url("https://"));
+        assert!(!is_url("://x"));
+        assert!(!is_url("ht tp://x"));
+    }
+}
@@ -0,0 +1,138 @@
+use rusqlite::{params, Connection, Result as SqlResult};
+
+use crate::args::{ArgKind, Args, Signature};
+use crate::command::{Command, CommandError, CommandResult, Output};
+
+// --- Comanda Bookmark pentru Bonus (P2) ---
+
+pub struct BookmarkCommand {
+    conn: Connection,
+}
+
+impl BookmarkCommand {
+    /// Deschide (sau creeaza) baza de date `bookmarks.db` din directorul curent
+    pub fn new() -> SqlResult<Self> {
+        Self::with_connection(Connection::open("bookmarks.db")?)
+    }
+
+    /// Foloseste o conexiune existenta (ex: `Connection::open_in_memory()` in teste)
+    pub fn with_connection(conn: Connection) -> SqlResult<Self> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS bookmarks (
+                name TEXT NOT NULL,
+                url  TEXT NOT NULL
+            )",
+            (),
+        )?;
+        Ok(BookmarkCommand { conn })
+    }
+
+    fn add(&self, name: &str, url: &str) -> CommandResult {
+        self.conn
+            .execute("INSERT INTO bookmarks (name, url) VALUES (?1, ?2)", params![name, url])
+            .map_err(|e| CommandError::failed(format!("could not add bookmark: {}", e)))?;
+        Ok(Output::line("Bookmark added successfully."))
+    }
+
+    /// `limit` negativ inseamna fara limita (conventia SQLite pentru `LIMIT`)
+    fn search(&self, query: &str, limit: i64) -> CommandResult {
+        let search_failed = |e: rusqlite::Error| CommandError::failed(format!("search failed: {}", e));
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name, url FROM bookmarks WHERE name LIKE ?1 LIMIT ?2")
+            .map_err(search_failed)?;
+
+        let search_pattern = format!("%{}%", query);
+        let bookmarks = stmt
+            .query_map(params![search_pattern, limit], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .and_then(|rows| rows.collect::<SqlResult<Vec<_>>>())
+            .map_err(search_failed)?;
+
+        let mut output = Output::line(format!("Search results for '{}':", query));
+        for (name, url) in &bookmarks {
+            output.push(format!("  {} -> {}", name, url));
+        }
+        if bookmarks.is_empty() {
+            output.push("  No bookmarks found.");
+        }
+        Ok(output)
+    }
+}
+
+impl Command for BookmarkCommand {
+    fn get_name(&self) -> &'static str { "bk" }
+
+    fn description(&self) -> &'static str { "Save and search bookmarks stored in bookmarks.db" }
+
+    fn signature(&self) -> Signature {
+        Signature::new()
+            .subcommand(
+                "add",
+                Signature::new()
+                    .about("Save a bookmark under a name")
+                    .required("name", ArgKind::Text)
+                    .required("url", ArgKind::Url),
+            )
+            .subcommand(
+                "search",
+                Signature::new()
+                    .about("List bookmarks whose name contains the given text")
+                    .required("name", ArgKind::Text)
+                    .option("limit", Some('n'), ArgKind::Integer),
+            )
+    }
+
+    fn examples(&self) -> &'static [&'static str] {
+        &["bk add rust https://rust-lang.org", "bk search rust", "bk search rust --limit 5"]
+    }
+
+    fn exec(&mut self, args: &Args, _: &[String]) -> CommandResult {
+        // Numarul si tipul argumentelor au fost deja verificate de `Terminal`
+        let name = args.get_str("name").unwrap_or_default();
+        match args.subcommand() {
+            Some("add") => self.add(name, args.get_str("url").unwrap_or_default()),
+            Some("search") => self.search(name, args.get_int("limit").unwrap_or(-1)),
+            _ => unreachable!("signature() declares only 'add' and 'search'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(bk: &mut BookmarkCommand, args: &[&str]) -> String {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        let args = bk.signature().parse(&args).unwrap();
+        bk.exec(&args, &[]).unwrap().to_string()
+    }
+
+    #[test]
+    fn add_and_search_in_memory() {
+        let mut bk = BookmarkCommand::with_connection(Connection::open_in_memory().unwrap()).unwrap();
+        assert_eq!(run(&mut bk, &["add", "rust", "https://rust-lang.org"]), "Bookmark added successfully.\n");
+        assert_eq!(
+            run(&mut bk, &["search", "ru"]),
+            "Search results for 'ru':\n  rust -> https://rust-lang.org\n"
+        );
+        assert_eq!(run(&mut bk, &["search", "go"]), "Search results for 'go':\n  No bookmarks found.\n");
+
+        run(&mut bk, &["add", "rustup", "https://rustup.rs"]);
+        assert_eq!(
+            run(&mut bk, &["search", "rust", "--limit", "1"]),
+            "Search results for 'rust':\n  rust -> https://rust-lang.org\n"
+        );
+    }
+
+    #[test]
+    fn database_errors_become_command_errors() {
+        let mut bk = BookmarkCommand::with_connection(Connection::open_in_memory().unwrap()).unwrap();
+        bk.conn.execute("DROP TABLE bookmarks", ()).unwrap();
+        let args = bk.signature().parse(&["search".to_string(), "x".to_string()]).unwrap();
+        let err = bk.exec(&args, &[]).unwrap_err();
+        assert_eq!(err.exit_code(), 1);
+        assert!(err.to_string().starts_with("search failed: no such table"), "{}", err);
+    }
+}
@@ -0,0 +1,150 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+use crate::args::{ArgError, Args, Signature};
+use crate::suggest::quote_list;
+
+// --- Definirea Trait-ului pentru Comenzi ---
+
+/// O comanda care poate fi inregistrata in `Terminal`.
+///
+/// `Terminal` valideaza argumentele cu `signature()` inainte de `exec`, care
+/// primeste valorile deja verificate si, intr-un pipeline (`a | b`), liniile
+/// produse de comanda precedenta ca `input` (gol altfel). Comanda nu scrie nimic
+/// direct: intoarce liniile de afisat (`Output`) sau o eroare, iar `Terminal`
+/// decide cum le afiseaza sau le trimite mai departe.
+pub trait Command {
+    fn get_name(&self) -> &'static str;
+
+    /// O propozitie afisata de `help`
+    fn description(&self) -> &'static str { "" }
+
+    /// Argumentele acceptate; implicit orice lista de cuvinte
+    fn signature(&self) -> Signature { Signature::any() }
+
+    /// Liniile `Usage:`; implicit generate din `signature()`
+    fn usage(&self) -> Vec<String> { self.signature().usage_lines(self.get_name()) }
+
+    /// Linii de comanda complete, afisate de `help <cmd>`
+    fn examples(&self) -> &'static [&'static str] { &[] }
+
+    fn exec(&mut self, args: &Args, input: &[String]) -> CommandResult;
+}
+
+pub type CommandResult = Result<Output, CommandError>;
+
+// --- Rezultatul unei comenzi ---
+
+/// Liniile produse de o comanda (fara `\n` la final)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Output {
+    lines: Vec<String>,
+}
+
+impl Output {
+    /// Fara nicio linie de afisat
+    pub fn new() -> Self {
+        Output::default()
+    }
+
+    pub fn line(text: impl Into<String>) -> Self {
+        Output { lines: vec![text.into()] }
+    }
+
+    pub fn push(&mut self, text: impl Into<String>) {
+        self.lines.push(text.into());
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn into_lines(self) -> Vec<String> {
+        self.lines
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Output {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Output { lines: iter.into_iter().map(Into::into).collect() }
+    }
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+// --- Erorile comenzilor ---
+
+#[derive(Debug)]
+pub enum CommandError {
+    /// Linia nu a putut fi impartita in cuvinte (ex: ghilimele neinchise)
+    Syntax(String),
+    /// `suggestions`: comenzile cu nume apropiat, cea mai apropiata prima
+    UnknownCommand { name: String, suggestions: Vec<String> },
+    /// Argumentele nu respecta `signature()`; detectat de `Terminal` inainte de `exec`
+    InvalidArgs { error: ArgError, usage: String },
+    /// Comanda a rulat, dar a esuat (ex: eroare SQLite)
+    Failed(String),
+    Io(io::Error),
+}
+
+impl CommandError {
+    pub fn failed(message: impl Into<String>) -> Self {
+        CommandError::Failed(message.into())
+    }
+
+    /// Codul de iesire, dupa conventiile shell-ului: 2 pentru utilizare gresita,
+    /// 127 pentru comanda inexistenta, 1 pentru orice alt esec
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CommandError::Syntax(_) | CommandError::InvalidArgs { .. } => 2,
+            CommandError::UnknownCommand { .. } => 127,
+            CommandError::Failed(_) | CommandError::Io(_) => 1,
+        }
+    }
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Syntax(message) | CommandError::Failed(message) => write!(f, "{}", message),
+            CommandError::UnknownCommand { name, suggestions } => {
+                write!(f, "Unknown command: '{}'.", name)?;
+                if !suggestions.is_empty() {
+                    let suggestions: Vec<&str> = suggestions.iter().map(String::as_str).collect();
+                    write!(f, " Did you mean {}?", quote_list(&suggestions))?;
+                }
+                Ok(())
+            }
+            CommandError::InvalidArgs { error, .. } => write!(f, "{}", error),
+            CommandError::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl Error for CommandError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CommandError::InvalidArgs { error, .. } => Some(error),
+            CommandError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CommandError {
+    fn from(e: io::Error) -> Self {
+        CommandError::Io(e)
+    }
+}
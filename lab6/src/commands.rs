@@ -0,0 +1,78 @@
+use crate::args::{ArgKind, Args, Signature};
+use crate::command::{Command, CommandResult, Output};
+
+// --- Comenzile pentru P1 ---
+
+pub struct PingCommand;
+impl Command for PingCommand {
+    fn get_name(&self) -> &'static str { "ping" }
+    fn description(&self) -> &'static str { "Reply with 'pong!'" }
+    fn exec(&mut self, _: &Args, _: &[String]) -> CommandResult { Ok(Output::line("pong!")) }
+}
+
+pub struct CountCommand;
+impl Command for CountCommand {
+    fn get_name(&self) -> &'static str { "count" }
+    fn description(&self) -> &'static str { "Print how many arguments (or piped lines) were given" }
+    fn examples(&self) -> &'static [&'static str] { &["count a b c", "bk search rust | count"] }
+    fn exec(&mut self, args: &Args, input: &[String]) -> CommandResult {
+        // Fara argumente, intr-un pipeline numara liniile primite
+        if args.rest().is_empty() && !input.is_empty() {
+            return Ok(Output::line(format!("counted {} lines", input.len())));
+        }
+        Ok(Output::line(format!("counted {} args", args.rest().len())))
+    }
+}
+
+#[derive(Default)]
+pub struct TimesCommand { pub count: u32 }
+impl Command for TimesCommand {
+    fn get_name(&self) -> &'static str { "times" }
+    fn description(&self) -> &'static str { "Print how many times this command was called" }
+    fn exec(&mut self, _: &Args, _: &[String]) -> CommandResult {
+        self.count += 1;
+        Ok(Output::line(format!("command called {} times", self.count)))
+    }
+}
+
+// Comanda custom ceruta de P1
+pub struct HelloCommand;
+impl Command for HelloCommand {
+    fn get_name(&self) -> &'static str { "hello" }
+    fn description(&self) -> &'static str { "Greet the world, or whoever is named" }
+    fn signature(&self) -> Signature { Signature::new().rest("name", ArgKind::Text) }
+    fn examples(&self) -> &'static [&'static str] { &["hello", "hello dear team"] }
+    fn exec(&mut self, args: &Args, _: &[String]) -> CommandResult {
+        if args.rest().is_empty() {
+            Ok(Output::line("Hello, world!"))
+        } else {
+            Ok(Output::line(format!("Hello, {}!", args.rest().join(" "))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(cmd: &mut dyn Command, args: &[&str]) -> String {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        let args = cmd.signature().parse(&args).unwrap();
+        cmd.exec(&args, &[]).unwrap().to_string()
+    }
+
+    #[test]
+    fn p1_commands() {
+        assert_eq!(run(&mut PingCommand, &["abc"]), "pong!\n");
+        assert_eq!(run(&mut CountCommand, &["a", "b", "c"]), "counted 3 args\n");
+        let lines = ["x".to_string(), "y".to_string()];
+        let no_args = CountCommand.signature().parse(&[]).unwrap();
+        assert_eq!(CountCommand.exec(&no_args, &lines).unwrap(), Output::line("counted 2 lines"));
+        assert_eq!(run(&mut HelloCommand, &[]), "Hello, world!\n");
+        assert_eq!(run(&mut HelloCommand, &["dear", "team"]), "Hello, dear team!\n");
+
+        let mut times = TimesCommand::default();
+        run(&mut times, &[]);
+        assert_eq!(run(&mut times, &[]), "command called 2 times\n");
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+// --- Completarea cu TAB ---
+//
+// Tabela de completare se construieste din comenzile inregistrate (`Terminal::completions`):
+// primul cuvant al unei comenzi se completeaza cu numele comenzilor, al doilea cu
+// subcomenzile (`bk a<TAB>` -> `bk add`), iar dupa `help` cu numele si subcomenzile
+// comenzii cerute. Cuvintele de dupa `;`, `&&`, `||` si `|` incep o comanda noua.
+
+/// Numele comenzilor si subcomenzile fiecareia
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Completions {
+    commands: BTreeMap<String, Vec<String>>,
+}
+
+impl Completions {
+    pub fn new() -> Self {
+        Completions::default()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, subcommands: Vec<String>) {
+        self.commands.insert(name.into(), subcommands);
+    }
+
+    /// Pozitia de la care se inlocuieste textul si cuvintele care pot urma,
+    /// pentru cursorul aflat la `pos` (in octeti) in `line`
+    pub fn complete(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+        let before = &line[..pos];
+        // Doar ultima comanda din lant conteaza
+        let segment_start = before.rfind([';', '|', '&']).map_or(0, |i| i + 1);
+        let segment = &before[segment_start..];
+        let word_start = segment.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let prefix = &segment[word_start..];
+        let previous: Vec<&str> = segment[..word_start].split_whitespace().collect();
+
+        let candidates: Vec<&String> = match previous.as_slice() {
+            [] => self.commands.keys().collect(),
+            ["help"] => self.commands.keys().filter(|name| *name != "help").collect(),
+            [name] | ["help", name] => self.subcommands(name),
+            _ => Vec::new(),
+        };
+        let matches = candidates.into_iter().filter(|word| word.starts_with(prefix)).cloned().collect();
+        (segment_start + word_start, matches)
+    }
+
+    fn subcommands(&self, name: &str) -> Vec<&String> {
+        self.commands.get(name).map(|subs| subs.iter().collect()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Completions {
+        let mut completions = Completions::new();
+        completions.insert("bk", vec!["add".into(), "search".into()]);
+        completions.insert("help", vec![]);
+        completions.insert("hello", vec![]);
+        completions.insert("ping", vec![]);
+        completions
+    }
+
+    fn complete(line: &str) -> (usize, Vec<String>) {
+        table().complete(line, line.len())
+    }
+
+    #[test]
+    fn completes_command_names() {
+        assert_eq!(complete("he"), (0, vec!["hello".into(), "help".into()]));
+        assert_eq!(complete("").1.len(), 4);
+        assert_eq!(complete("ping ; p"), (7, vec!["ping".into()]));
+        assert_eq!(complete("bk search x | pi"), (14, vec!["ping".into()]));
+    }
+
+    #[test]
+    fn completes_subcommands() {
+        assert_eq!(complete("bk a"), (3, vec!["add".into()]));
+        assert_eq!(complete("bk  "), (4, vec!["add".into(), "search".into()]));
+        assert_eq!(complete("bk add x"), (7, vec![]));
+        assert_eq!(complete("ping a"), (5, vec![]));
+    }
+
+    #[test]
+    fn completes_help_topics() {
+        assert_eq!(complete("help b"), (5, vec!["bk".into()]));
+        assert_eq!(complete("help bk s"), (8, vec!["search".into()]));
+        assert_eq!(complete("help h"), (5, vec!["hello".into()]));
+    }
+
+    #[test]
+    fn completes_in_the_middle_of_a_line() {
+        let line = "bk se rust";
+        assert_eq!(table().complete(line, 5), (3, vec!["search".into()]));
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::pipeline::parse_line;
+use crate::script::expand;
+use crate::terminal::BUILTINS;
+use crate::tokenizer::{tokenize, Token};
+
+// --- Alias-uri si macro-uri definite de utilizator ---
+//
+// `alias b=bk search` inlocuieste numele comenzii: `b rust` ruleaza `bk search rust`.
+// `define greet = hello $1; ping` defineste o linie intreaga (cu `;`, `&&`, `|`),
+// in care `$1`..`$9` sunt argumentele, `$@` toate argumentele si `$#` numarul lor.
+// Definitiile ocupa o linie intreaga, pentru ca `;` face parte din corpul macro-ului.
+// In interiorul propriei expandari un nume nu se mai expandeaza: `alias ping=ping -x`
+// ruleaza comanda `ping`, iar fara o comanda cu acel nume expandarea este recursiva.
+
+/// O linie care defineste un alias sau un macro
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+    Alias { name: String, text: String },
+    Define { name: String, body: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinitionError {
+    /// Lipseste `=` intre nume si definitie
+    MissingEquals(&'static str),
+    InvalidName(String),
+    /// Numele este rezervat pentru o comanda interna (`help`, `alias`, ...)
+    ReservedName(String),
+    EmptyDefinition(String),
+    /// Textul unui alias trebuie sa fie o singura comanda, fara `;`, `&&`, `||`, `|`
+    AliasWithOperators(String),
+    /// Textul nu poate fi impartit in comenzi (ex: ghilimele neinchise)
+    InvalidBody { name: String, reason: String },
+}
+
+impl Display for DefinitionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DefinitionError::MissingEquals(keyword) => write!(f, "usage: {} <name>=<text>", keyword),
+            DefinitionError::InvalidName(name) => write!(f, "invalid name '{}'", name),
+            DefinitionError::ReservedName(name) => {
+                write!(f, "'{}' is a built-in command and cannot be redefined", name)
+            }
+            DefinitionError::EmptyDefinition(name) => write!(f, "empty definition for '{}'", name),
+            DefinitionError::AliasWithOperators(name) => {
+                write!(f, "alias '{}' must be a single command; use 'define' for chains", name)
+            }
+            DefinitionError::InvalidBody { name, reason } => write!(f, "invalid body for '{}': {}", name, reason),
+        }
+    }
+}
+
+impl Error for DefinitionError {}
+
+/// `None` daca linia nu este o definitie (si se executa normal, inclusiv `alias`
+/// sau `define` fara argumente, care listeaza definitiile)
+pub fn parse_statement(line: &str) -> Option<Result<Statement, DefinitionError>> {
+    let (keyword, rest) = line.trim().split_once(char::is_whitespace)?;
+    let rest = rest.trim();
+    match keyword {
+        _ if rest.is_empty() => None,
+        "alias" => Some(parse_alias(rest)),
+        "define" => Some(parse_define(rest)),
+        _ => None,
+    }
+}
+
+fn split_definition(keyword: &'static str, rest: &str) -> Result<(String, String), DefinitionError> {
+    let (name, text) = rest.split_once('=').ok_or(DefinitionError::MissingEquals(keyword))?;
+    let (name, text) = (name.trim(), text.trim());
+    check_name(name)?;
+    if text.is_empty() {
+        return Err(DefinitionError::EmptyDefinition(name.to_string()));
+    }
+    Ok((name.to_string(), text.to_string()))
+}
+
+fn parse_alias(rest: &str) -> Result<Statement, DefinitionError> {
+    let (name, text) = split_definition("alias", rest)?;
+    let tokens = tokenize(&text)
+        .map_err(|e| DefinitionError::InvalidBody { name: name.clone(), reason: e.to_string() })?;
+    if tokens.iter().any(|token| matches!(token, Token::Op(_))) {
+        return Err(DefinitionError::AliasWithOperators(name));
+    }
+    if tokens.is_empty() {
+        return Err(DefinitionError::EmptyDefinition(name));
+    }
+    Ok(Statement::Alias { name, text })
+}
+
+fn parse_define(rest: &str) -> Result<Statement, DefinitionError> {
+    let (name, body) = split_definition("define", rest)?;
+    parse_line(&body).map_err(|e| DefinitionError::InvalidBody { name: name.clone(), reason: e.to_string() })?;
+    Ok(Statement::Define { name, body })
+}
+
+/// Un nume este un singur cuvant fara ghilimele, `$`, `=` sau operatori
+fn check_name(name: &str) -> Result<(), DefinitionError> {
+    let valid = !name.is_empty()
+        && !name.starts_with('-')
+        && name.chars().all(|c| c.is_alphanumeric() || "_-.".contains(c));
+    if !valid {
+        return Err(DefinitionError::InvalidName(name.to_string()));
+    }
+    if BUILTINS.contains(&name) {
+        return Err(DefinitionError::ReservedName(name.to_string()));
+    }
+    Ok(())
+}
+
+// --- Tabela de definitii ---
+
+#[derive(Debug, Clone, Default)]
+pub struct Definitions {
+    aliases: BTreeMap<String, String>,
+    macros: BTreeMap<String, String>,
+}
+
+impl Definitions {
+    /// Un nume este fie alias, fie macro: o definitie noua o inlocuieste pe cea veche
+    pub fn set_alias(&mut self, name: String, text: String) {
+        self.macros.remove(&name);
+        self.aliases.insert(name, text);
+    }
+
+    pub fn set_macro(&mut self, name: String, body: String) {
+        self.aliases.remove(&name);
+        self.macros.insert(name, body);
+    }
+
+    /// `true` daca exista o definitie cu acest nume
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.aliases.remove(name).is_some() | self.macros.remove(name).is_some()
+    }
+
+    pub fn alias(&self, name: &str) -> Option<&str> {
+        self.aliases.get(name).map(String::as_str)
+    }
+
+    pub fn macro_body(&self, name: &str) -> Option<&str> {
+        self.macros.get(name).map(String::as_str)
+    }
+
+    pub fn aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.aliases.iter().map(|(name, text)| (name.as_str(), text.as_str()))
+    }
+
+    pub fn macros(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.macros.iter().map(|(name, body)| (name.as_str(), body.as_str()))
+    }
+}
+
+// --- Substitutia argumentelor ---
+
+/// Inlocuieste `$1`..`$9`, `$@` si `$#` din `body` cu argumentele macro-ului (vezi
+/// `script::expand`): `$@` da cate un cuvant pentru fiecare argument. Celelalte
+/// `$...` (variabilele) raman pentru expandarea de la rulare.
+pub fn substitute_args(body: &str, args: &[String]) -> String {
+    expand(body, |name| match name {
+        "@" => Some(args.to_vec()),
+        "#" => Some(vec![args.len().to_string()]),
+        "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" => {
+            let index = name.parse::<usize>().unwrap_or(1) - 1;
+            Some(vec![args.get(index).cloned().unwrap_or_default()])
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn words(line: &str) -> Vec<String> {
+        tokenize(line)
+            .unwrap()
+            .into_iter()
+            .map(|token| match token {
+                Token::Word(word) => word,
+                Token::Op(op) => op.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_statements() {
+        assert_eq!(parse_statement("ping"), None);
+        assert_eq!(parse_statement("aliases x"), None);
+        assert_eq!(
+            parse_statement("alias b=bk search"),
+            Some(Ok(Statement::Alias { name: "b".into(), text: "bk search".into() }))
+        );
+        assert_eq!(
+            parse_statement("  define greet = hello team; ping"),
+            Some(Ok(Statement::Define { name: "greet".into(), body: "hello team; ping".into() }))
+        );
+        assert_eq!(parse_statement("alias"), None);
+        assert_eq!(parse_statement("define  "), None);
+    }
+
+    #[test]
+    fn rejects_bad_definitions() {
+        let err = |line: &str| parse_statement(line).unwrap().unwrap_err().to_string();
+        assert_eq!(err("alias b"), "usage: alias <name>=<text>");
+        assert_eq!(err("alias a b=ping"), "invalid name 'a b'");
+        assert_eq!(err("define help = ping"), "'help' is a built-in command and cannot be redefined");
+        assert_eq!(err("alias x = "), "empty definition for 'x'");
+        assert_eq!(err("alias x = # nothing"), "empty definition for 'x'");
+        assert_eq!(err("alias x = ping; ping"), "alias 'x' must be a single command; use 'define' for chains");
+        assert_eq!(err("define x = ping &&"), "invalid body for 'x': syntax error: missing command around '&&'");
+    }
+
+    #[test]
+    fn substitutes_arguments() {
+        let a = args(&["dear team", "it's"]);
+        assert_eq!(words(&substitute_args("hello $1", &a)), ["hello", "dear team"]);
+        assert_eq!(words(&substitute_args("count $@ ; x $#", &a)), ["count", "dear team", "it's", ";", "x", "2"]);
+        assert_eq!(
+            words(&substitute_args("hello \"<$@>\" '$1' $3 \\$1", &a)),
+            ["hello", "<dear team it's>", "$1", "", "$1"]
+        );
+        assert_eq!(substitute_args("cost $5x $", &[]), "cost ''x $");
+    }
+
+    #[test]
+    fn one_definition_per_name() {
+        let mut defs = Definitions::default();
+        defs.set_alias("x".into(), "ping".into());
+        defs.set_macro("x".into(), "ping; ping".into());
+        assert_eq!(defs.alias("x"), None);
+        assert_eq!(defs.macro_body("x"), Some("ping; ping"));
+        assert!(defs.remove("x"));
+        assert!(!defs.remove("x"));
+    }
+}
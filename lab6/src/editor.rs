@@ -0,0 +1,108 @@
+use std::io;
+use std::path::Path;
+
+use rustyline::completion::{Completer, Pair};
+use rustyline::config::Config;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{CompletionType, Context, Editor, Helper};
+
+use crate::completion::Completions;
+use crate::terminal::{Flow, Terminal};
+use crate::tokenizer::needs_continuation;
+
+// --- Modul interactiv cu editor de linie ---
+//
+// Sageti si scurtaturi Emacs pentru editare, istoric (sus/jos, Ctrl-R pentru
+// cautare inapoi) salvat intr-un fisier intre sesiuni, si completare cu TAB.
+
+/// Legatura dintre rustyline si tabela de completare a terminalului
+struct ShellHelper {
+    completions: Completions,
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let (start, words) = self.completions.complete(line, pos);
+        let pairs = words
+            .into_iter()
+            .map(|word| Pair { replacement: format!("{} ", word), display: word })
+            .collect();
+        Ok((start, pairs))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+/// Ruleaza `terminal` interactiv pana la `stop`, Ctrl-D sau o eroare de citire.
+///
+/// Istoricul se incarca din `history` (daca exista) si se salveaza la iesire.
+/// Ctrl-C renunta la linia curenta, fara sa opreasca terminalul.
+pub fn run_editor(terminal: &mut Terminal, history: Option<&Path>) -> Result<(), ReadlineError> {
+    let config = Config::builder()
+        .completion_type(CompletionType::List)
+        .history_ignore_dups(true)?
+        .history_ignore_space(true)
+        .max_history_size(1000)?
+        .build();
+    let mut editor: Editor<ShellHelper, DefaultHistory> = Editor::with_config(config)?;
+    editor.set_helper(Some(ShellHelper { completions: terminal.completions() }));
+    if let Some(path) = history {
+        // La prima rulare fisierul nu exista inca
+        let _ = editor.load_history(path);
+    }
+
+    let mut stdout = io::stdout();
+    'lines: loop {
+        // Comenzile pot aparea intre linii (ex: `alias`), deci reconstruim tabela
+        if let Some(helper) = editor.helper_mut() {
+            helper.completions = terminal.completions();
+        }
+        // In interiorul unui bloc `if`/`for` promptul arata ca linia continua blocul
+        let prompt = if terminal.in_block() { ". " } else { "> " };
+        let mut line = match editor.readline(prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => {
+                terminal.finish(&mut stdout)?;
+                break;
+            }
+            Err(e) => return Err(e),
+        };
+        while needs_continuation(&line) {
+            match editor.readline(". ") {
+                Ok(next) => {
+                    line.push('\n');
+                    line.push_str(&next);
+                }
+                // Ctrl-C renunta la toata comanda, inclusiv liniile deja continuate
+                Err(ReadlineError::Interrupted) => continue 'lines,
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        editor.add_history_entry(line.as_str())?;
+        if terminal.process_line(&line, &mut stdout)? == Flow::Stop {
+            break;
+        }
+    }
+
+    if let Some(path) = history {
+        editor.save_history(path)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// --- Biblioteca pentru terminalul de comenzi ---
+//
+// `Terminal` tine comenzile inregistrate si le executa linie cu linie, din orice
+// `BufRead` (fisier, stdin, un `&[u8]` in teste), scriind rezultatul in orice `Write`.
+// Comenzile implementeaza trait-ul `Command`: intorc un `Output` sau un `CommandError`
+// si nu ating direct stdin/stdout.
+
+pub mod args;
+pub mod bookmark;
+pub mod command;
+pub mod commands;
+pub mod completion;
+pub mod definitions;
+pub mod editor;
+pub mod pipeline;
+pub mod plugin;
+pub mod script;
+pub mod suggest;
+pub mod terminal;
+pub mod tokenizer;
+
+pub use args::{ArgError, ArgKind, Args, Signature};
+pub use bookmark::BookmarkCommand;
+pub use command::{Command, CommandError, CommandResult, Output};
+pub use commands::{CountCommand, HelloCommand, PingCommand, TimesCommand};
+pub use completion::Completions;
+pub use definitions::{Definitions, DefinitionError, Statement};
+pub use editor::run_editor;
+pub use pipeline::{parse_line, split_steps, Condition, Step, SyntaxError};
+pub use plugin::{load_plugin, register_plugins, PluginError, PLUGIN_ABI_VERSION};
+pub use script::{parse_script, Node, ScriptError};
+pub use terminal::{Flow, Terminal};
+pub use tokenizer::{tokenize, Operator, Token, TokenizeError};
@@ -1,232 +1,79 @@
+use std::env;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader, Write};
-use rusqlite::{Connection, Result as SqlResult, params};
-
-// --- Definirea Trait-ului pentru Comenzi ---
-trait Command {
-    fn get_name(&self) -> &'static str;
-    fn exec(&mut self, args: &[String]);
-}
-
-// --- Comenzile pentru P1 ---
-
-struct PingCommand;
-impl Command for PingCommand {
-    fn get_name(&self) -> &'static str { "ping" }
-    fn exec(&mut self, _: &[String]) { println!("pong!"); }
-}
-
-struct CountCommand;
-impl Command for CountCommand {
-    fn get_name(&self) -> &'static str { "count" }
-    fn exec(&mut self, args: &[String]) { println!("counted {} args", args.len()); }
+use std::io::{self, BufReader, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use laborator_traits::{
+    register_plugins, run_editor, BookmarkCommand, CountCommand, HelloCommand, PingCommand, Terminal, TimesCommand,
+};
+
+/// Istoricul modului interactiv: `~/.laborator_history` (sau in directorul curent)
+fn history_path() -> PathBuf {
+    let home = env::var_os("HOME").map(PathBuf::from).unwrap_or_default();
+    home.join(".laborator_history")
 }
 
-struct TimesCommand { count: u32 }
-impl Command for TimesCommand {
-    fn get_name(&self) -> &'static str { "times" }
-    fn exec(&mut self, _: &[String]) {
-        self.count += 1;
-        println!("command called {} times", self.count);
-    }
-}
-
-// Comanda custom ceruta de P1
-struct HelloCommand;
-impl Command for HelloCommand {
-    fn get_name(&self) -> &'static str { "hello" }
-    fn exec(&mut self, args: &[String]) {
-        if args.is_empty() {
-            println!("Hello, world!");
-        } else {
-            println!("Hello, {}!", args.join(" "));
-        }
-    }
+/// Alias-urile si macro-urile incarcate la pornire: `~/.laboratorrc`
+fn config_path() -> PathBuf {
+    let home = env::var_os("HOME").map(PathBuf::from).unwrap_or_default();
+    home.join(".laboratorrc")
 }
 
-// --- Comanda Bookmark pentru Bonus (P2) ---
-
-struct BookmarkCommand {
-    conn: Connection,
-}
+fn main() {
+    let mut terminal = Terminal::new();
 
-impl BookmarkCommand {
-    fn new() -> SqlResult<Self> {
-        let conn = Connection::open("bookmarks.db")?;
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS bookmarks (
-                name TEXT NOT NULL,
-                url  TEXT NOT NULL
-            )",
-            (),
-        )?;
-        Ok(BookmarkCommand { conn })
-    }
+    terminal.register(Box::new(PingCommand));
+    terminal.register(Box::new(CountCommand));
+    terminal.register(Box::new(TimesCommand { count: 0 }));
+    terminal.register(Box::new(HelloCommand));
 
-    fn add(&self, name: &str, url: &str) {
-        match self.conn.execute("INSERT INTO bookmarks (name, url) VALUES (?1, ?2)", params![name, url]) {
-            Ok(_) => println!("Bookmark added successfully."),
-            Err(e) => println!("Error adding bookmark: {}", e),
-        }
+    // Register Bookmark command only if DB connection succeeds
+    match BookmarkCommand::new() {
+        Ok(bk_cmd) => terminal.register(Box::new(bk_cmd)),
+        Err(e) => println!("Failed to initialize BookmarkCommand (SQLite error): {}", e),
     }
 
-    fn search(&self, query: &str) {
-        let mut stmt = match self.conn.prepare("SELECT name, url FROM bookmarks WHERE name LIKE ?1") {
-            Ok(s) => s,
-            Err(e) => { println!("Error preparing search query: {}", e); return; }
-        };
-
-        let search_pattern = format!("%{}%", query);
-        let bookmark_iter = match stmt.query_map(params![search_pattern], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-        }) {
-            Ok(iter) => iter,
-            Err(e) => { println!("Error executing search query: {}", e); return; }
-        };
-
-        println!("Search results for '{}':", query);
-        let mut found = false;
-        for bookmark in bookmark_iter {
-            if let Ok((name, url)) = bookmark {
-                println!("  {} -> {}", name, url);
-                found = true;
-            }
-        }
-        if !found {
-            println!("  No bookmarks found.");
+    // Fisierul de configurare este optional; o linie gresita este raportata, dar nu opreste programul
+    let config = config_path();
+    if let Ok(file) = File::open(&config) {
+        if let Err(e) = terminal.load_definitions(BufReader::new(file)) {
+            println!("Error in {}: {}", config.display(), e);
         }
     }
-}
-
-impl Command for BookmarkCommand {
-    fn get_name(&self) -> &'static str { "bk" }
 
-    fn exec(&mut self, args: &[String]) {
-        if args.is_empty() {
-            println!("Usage: bk add <name> <url> OR bk search <name>");
-            return;
-        }
+    // `--stop-on-error`: scriptul se opreste la prima comanda esuata
+    // `--autocorrect`: o comanda necunoscuta cu o singura sugestie ruleaza sugestia
+    // `--plugins=DIR`: directorul cu plugin-uri (implicit `plugins/`)
+    // Primul argument care nu este flag este scriptul de rulat (implicit `commands.txt`)
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (flags, scripts): (Vec<&String>, Vec<&String>) = args.iter().partition(|arg| arg.starts_with("--"));
 
-        match args[0].as_str() {
-            "add" => {
-                if args.len() == 3 {
-                    self.add(&args[1], &args[2]);
-                } else {
-                    println!("Usage: bk add <name> <url>");
-                }
-            }
-            "search" => {
-                if args.len() == 2 {
-                    self.search(&args[1]);
-                } else {
-                    println!("Usage: bk search <name>");
-                }
-            }
-            _ => println!("Unknown bk subcommand. Use 'add' or 'search'."),
-        }
+    let plugins = flags.iter().rev().find_map(|arg| arg.strip_prefix("--plugins=")).unwrap_or("plugins");
+    for e in register_plugins(&mut terminal, Path::new(plugins)) {
+        println!("Failed to load plugin {}", e);
     }
-}
-
-// --- Structura Terminal ---
+    terminal.set_stop_on_error(flags.iter().any(|arg| *arg == "--stop-on-error"));
+    terminal.set_autocorrect(flags.iter().any(|arg| *arg == "--autocorrect"));
 
-struct Terminal {
-    commands: Vec<Box<dyn Command>>,
-}
-
-impl Terminal {
-    fn new() -> Self {
-        Terminal { commands: Vec::new() }
-    }
-
-    fn register(&mut self, command: Box<dyn Command>) {
-        self.commands.push(command);
-    }
-
-    fn run(&mut self) {
-        let file = match File::open("commands.txt") {
-            Ok(f) => f,
+    let result = match scripts.first() {
+        Some(script) => terminal.run_file(Path::new(script.as_str()), &mut io::stdout()),
+        None => match File::open("commands.txt") {
+            Ok(file) => terminal.run(BufReader::new(file), io::stdout()),
             Err(_) => {
                 println!("Could not open commands.txt. Reading from stdin instead (type 'stop' to quit):");
-                self.run_interactive();
-                return;
-            }
-        };
-
-        let reader = BufReader::new(file);
-        for line in reader.lines() {
-            if let Ok(line_content) = line {
-                self.process_line(&line_content);
-                if line_content.trim() == "stop" { return; }
-            }
-        }
-    }
-
-    fn run_interactive(&mut self) {
-        let stdin = io::stdin();
-        let mut handle = stdin.lock();
-        let mut buffer = String::new();
-
-        loop {
-            print!("> ");
-            io::stdout().flush().unwrap();
-            buffer.clear();
-            match handle.read_line(&mut buffer) {
-                Ok(0) => break, // EOF
-                Ok(_) => {
-                    let trimmed = buffer.trim();
-                    if trimmed == "stop" { break; }
-                    self.process_line(trimmed);
+                if io::stdin().is_terminal() {
+                    run_editor(&mut terminal, Some(&history_path())).map_err(io::Error::other)
+                } else {
+                    terminal.run_interactive(io::stdin().lock(), io::stdout())
                 }
-                Err(e) => { println!("Error reading line: {}", e); break; }
-            }
-        }
-    }
-
-    fn process_line(&mut self, line: &str) {
-        let parts: Vec<String> = line.split_whitespace().map(|s| s.to_string()).collect();
-        if parts.is_empty() { return; }
-
-        let cmd_name = &parts[0];
-        let args = &parts[1..];
-
-        if cmd_name == "stop" { return; }
-
-        let mut found = false;
-        for cmd in &mut self.commands {
-            if cmd.get_name() == cmd_name {
-                cmd.exec(args);
-                found = true;
-                break;
             }
-        }
+        },
+    };
 
-        if !found {
-            print!("Unknown command: '{}'.", cmd_name);
-             // Simple suggestion logic (case-insensitive check)
-             for cmd in &self.commands {
-                if cmd.get_name().eq_ignore_ascii_case(cmd_name) {
-                    print!(" Did you mean '{}'?", cmd.get_name());
-                    break;
-                }
-            }
-            println!();
-        }
+    if let Err(e) = result {
+        println!("Error reading line: {}", e);
+        process::exit(1);
     }
+    process::exit(terminal.last_status());
 }
-
-fn main() {
-    let mut terminal = Terminal::new();
-
-    terminal.register(Box::new(PingCommand));
-    terminal.register(Box::new(CountCommand));
-    terminal.register(Box::new(TimesCommand { count: 0 }));
-    terminal.register(Box::new(HelloCommand));
-
-    // Register Bookmark command only if DB connection succeeds
-    match BookmarkCommand::new() {
-        Ok(bk_cmd) => terminal.register(Box::new(bk_cmd)),
-        Err(e) => println!("Failed to initialize BookmarkCommand (SQLite error): {}", e),
-    }
-
-    terminal.run();
-}
\ No newline at end of file
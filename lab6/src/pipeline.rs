@@ -0,0 +1,192 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::tokenizer::{tokenize, tokenize_spans, Operator, Token, TokenizeError};
+
+// --- Inlantuirea comenzilor pe o linie ---
+//
+// `ping && hello team ; bk search rust | count` devine o lista de pasi; fiecare
+// pas este un pipeline (comenzi legate prin `|`) cu conditia de rulare data de
+// operatorul dinaintea lui (`;`, `&&` sau `||`), ca in shell.
+
+/// Numele comenzii urmat de argumente (niciodata gol)
+pub type CommandLine = Vec<String>;
+
+/// Comenzi legate prin `|`
+pub type Pipeline = Vec<CommandLine>;
+
+/// Cand ruleaza un pas, in functie de codul de iesire al pasului precedent
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Condition {
+    Always,
+    OnSuccess,
+    OnFailure,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub condition: Condition,
+    pub pipeline: Pipeline,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxError {
+    Tokenize(TokenizeError),
+    /// Un operator fara comanda de o parte (ex: `| count`, `ping &&`)
+    MissingCommand(Operator),
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SyntaxError::Tokenize(e) => write!(f, "{}", e),
+            SyntaxError::MissingCommand(op) => write!(f, "syntax error: missing command around '{}'", op),
+        }
+    }
+}
+
+impl Error for SyntaxError {}
+
+impl From<TokenizeError> for SyntaxError {
+    fn from(e: TokenizeError) -> Self {
+        SyntaxError::Tokenize(e)
+    }
+}
+
+/// Imparte linia in pasi; o linie goala (sau doar comentariu) nu are niciun pas.
+/// Un `;` final este permis, ceilalti operatori cer o comanda de ambele parti.
+pub fn parse_line(line: &str) -> Result<Vec<Step>, SyntaxError> {
+    let mut steps = Vec::new();
+    let mut pipeline = Vec::new();
+    let mut command = Vec::new();
+    let mut condition = Condition::Always;
+    let mut last_op = None;
+
+    for token in tokenize(line)? {
+        match token {
+            Token::Word(word) => {
+                command.push(word);
+                last_op = None;
+            }
+            Token::Op(op) => {
+                if command.is_empty() {
+                    return Err(SyntaxError::MissingCommand(op));
+                }
+                pipeline.push(std::mem::take(&mut command));
+                condition = match op {
+                    Operator::Pipe => condition,
+                    _ => {
+                        steps.push(Step {
+                            condition,
+                            pipeline: std::mem::take(&mut pipeline),
+                        });
+                        condition_after(op)
+                    }
+                };
+                last_op = Some(op);
+            }
+        }
+    }
+
+    match last_op {
+        None if !command.is_empty() => {
+            pipeline.push(command);
+            steps.push(Step { condition, pipeline });
+        }
+        None | Some(Operator::Then) => {}
+        Some(op) => return Err(SyntaxError::MissingCommand(op)),
+    }
+    Ok(steps)
+}
+
+/// Conditia pasului care urmeaza dupa `;`, `&&` sau `||`
+fn condition_after(op: Operator) -> Condition {
+    match op {
+        Operator::And => Condition::OnSuccess,
+        Operator::Or => Condition::OnFailure,
+        _ => Condition::Always,
+    }
+}
+
+/// Ca `parse_line`, dar intoarce textul fiecarui pas asa cum apare in linie, neexpandat:
+/// `ping && hello $x` -> `[(Always, "ping "), (OnSuccess, " hello $x")]`.
+/// Terminalul expandeaza variabilele dintr-un pas abia cand il ruleaza, ca `$?`
+/// si variabilele setate de pasii precedenti sa fie la zi.
+pub fn split_steps(line: &str) -> Result<Vec<(Condition, &str)>, SyntaxError> {
+    // Erorile de sintaxa se raporteaza pentru toata linia, inainte sa ruleze ceva
+    parse_line(line)?;
+    let mut steps = Vec::new();
+    let (mut start, mut condition, mut has_words) = (0, Condition::Always, false);
+    for (token, end) in tokenize_spans(line)? {
+        match token {
+            Token::Word(_) => has_words = true,
+            Token::Op(Operator::Pipe) => {}
+            Token::Op(op) => {
+                steps.push((condition, &line[start..end - op.to_string().len()]));
+                (start, condition, has_words) = (end, condition_after(op), false);
+            }
+        }
+    }
+    if has_words {
+        steps.push((condition, &line[start..]));
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(condition: Condition, pipeline: &[&str]) -> Step {
+        let pipeline = pipeline
+            .iter()
+            .map(|cmd| cmd.split_whitespace().map(String::from).collect())
+            .collect();
+        Step { condition, pipeline }
+    }
+
+    #[test]
+    fn chains_and_pipelines() {
+        assert_eq!(
+            parse_line("ping && hello team ; bk search rust | count || times;").unwrap(),
+            [
+                step(Condition::Always, &["ping"]),
+                step(Condition::OnSuccess, &["hello team"]),
+                step(Condition::Always, &["bk search rust", "count"]),
+                step(Condition::OnFailure, &["times"]),
+            ]
+        );
+        assert!(parse_line("  # nothing").unwrap().is_empty());
+    }
+
+    #[test]
+    fn splits_step_text() {
+        assert_eq!(
+            split_steps(r"nope;hello $? && bk add 'a;b' | count || \; x # c; d").unwrap(),
+            [
+                (Condition::Always, "nope"),
+                (Condition::Always, "hello $? "),
+                (Condition::OnSuccess, " bk add 'a;b' | count "),
+                (Condition::OnFailure, r" \; x # c; d"),
+            ]
+        );
+        assert_eq!(split_steps("ping; # only a comment").unwrap(), [(Condition::Always, "ping")]);
+        assert_eq!(split_steps("ping &&"), Err(SyntaxError::MissingCommand(Operator::And)));
+    }
+
+    #[test]
+    fn operators_need_commands() {
+        for (line, op) in [
+            ("| count", Operator::Pipe),
+            ("ping &&", Operator::And),
+            ("a ;; b", Operator::Then),
+            ("a |", Operator::Pipe),
+        ] {
+            assert_eq!(parse_line(line), Err(SyntaxError::MissingCommand(op)), "{}", line);
+        }
+        assert_eq!(
+            parse_line("ping ||").unwrap_err().to_string(),
+            "syntax error: missing command around '||'"
+        );
+    }
+}
@@ -0,0 +1,541 @@
+use std::error::Error;
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{Command as Process, Stdio};
+
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+
+use crate::args::Args;
+use crate::command::{Command, CommandError, CommandResult, Output};
+use crate::terminal::Terminal;
+
+// --- Plugin-uri: comenzi incarcate la rulare ---
+//
+// Fiecare fisier din directorul de plugin-uri (implicit `plugins/`) adauga o comanda:
+//
+// - o biblioteca dinamica (`.so`, `.dylib`, `.dll`) care exporta, cu ABI C,
+//   `laborator_plugin_abi_version() -> u32` si `laborator_plugin_declare() -> PluginDeclaration`;
+//   cel mai simplu se scrie cu macro-ul `export_plugin!` (vezi `examples/upper_plugin.rs`)
+// - un executabil care vorbeste JSON pe stdin/stdout, cate o cerere per proces:
+//     -> {"request":"describe","abi_version":1}
+//     <- {"abi_version":1,"name":"shout","description":"..."}
+//     -> {"request":"exec","abi_version":1,"args":["a"],"input":["linie"]}
+//     <- {"status":0,"output":["..."]}   sau   {"status":1,"error":"mesaj"}
+//
+// Versiunea ABI se verifica inainte de orice alt apel; la o nepotrivire plugin-ul nu
+// se incarca. Numele si descrierea plugin-urilor se aloca o singura data si raman in
+// memorie pana la final (`Command::get_name` intoarce `&'static str`).
+
+/// Versiunea interfetei cu plugin-urile; se schimba la orice modificare incompatibila
+/// a `PluginDeclaration` sau a protocolului JSON
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Primeste de la plugin o linie de iesire (UTF-8 terminat cu NUL)
+pub type EmitFn = unsafe extern "C" fn(context: *mut c_void, line: *const c_char);
+
+/// Ruleaza comanda: argumentele si liniile de intrare sunt siruri UTF-8 terminate cu NUL.
+/// Intoarce 0 la succes; altfel liniile emise formeaza mesajul de eroare.
+pub type ExecFn = unsafe extern "C" fn(
+    args: *const *const c_char,
+    arg_count: usize,
+    input: *const *const c_char,
+    input_count: usize,
+    emit: EmitFn,
+    context: *mut c_void,
+) -> i32;
+
+/// Ce intoarce `laborator_plugin_declare` dintr-o biblioteca dinamica
+#[repr(C)]
+pub struct PluginDeclaration {
+    pub abi_version: u32,
+    pub name: *const c_char,
+    pub description: *const c_char,
+    pub exec: ExecFn,
+}
+
+#[derive(Debug)]
+pub enum PluginError {
+    Io { path: PathBuf, error: io::Error },
+    Library { path: PathBuf, error: libloading::Error },
+    /// Plugin-ul este construit pentru alta versiune a interfetei
+    AbiMismatch { path: PathBuf, found: u32 },
+    /// Declaratia sau raspunsul `describe` nu respecta protocolul
+    Invalid { path: PathBuf, reason: String },
+    /// Exista deja o comanda (sau comanda interna) cu acest nume
+    NameTaken { path: PathBuf, name: String },
+}
+
+impl Display for PluginError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            PluginError::Library { path, error } => write!(f, "{}: could not load library: {}", path.display(), error),
+            PluginError::AbiMismatch { path, found } => write!(
+                f,
+                "{}: built for plugin ABI version {}, but this terminal supports version {}",
+                path.display(),
+                found,
+                PLUGIN_ABI_VERSION
+            ),
+            PluginError::Invalid { path, reason } => write!(f, "{}: invalid plugin: {}", path.display(), reason),
+            PluginError::NameTaken { path, name } => {
+                write!(f, "{}: a command named '{}' is already registered", path.display(), name)
+            }
+        }
+    }
+}
+
+impl Error for PluginError {}
+
+/// Incarca toate plugin-urile din `dir`, in ordinea numelor de fisier, si le inregistreaza
+/// in `terminal`. Un plugin gresit nu le opreste pe celelalte: erorile se intorc la final.
+/// Un director inexistent inseamna ca nu exista plugin-uri.
+pub fn register_plugins(terminal: &mut Terminal, dir: &Path) -> Vec<PluginError> {
+    let mut entries: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Vec::new(),
+        Err(error) => return vec![PluginError::Io { path: dir.to_path_buf(), error }],
+    };
+    entries.sort();
+
+    let mut errors = Vec::new();
+    for path in entries.into_iter().filter(|path| is_plugin(path)) {
+        match load_plugin(&path) {
+            Ok(command) if terminal.has_command(command.get_name()) => {
+                errors.push(PluginError::NameTaken { path, name: command.get_name().to_string() })
+            }
+            Ok(command) => terminal.register(command),
+            Err(e) => errors.push(e),
+        }
+    }
+    errors
+}
+
+/// Bibliotecile dinamice si executabilele; restul fisierelor (ex: README) sunt ignorate
+fn is_plugin(path: &Path) -> bool {
+    is_library(path) || is_executable(path)
+}
+
+fn is_library(path: &Path) -> bool {
+    path.is_file() && path.extension().is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION)
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file() && path.extension().is_some_and(|ext| ext == std::env::consts::EXE_EXTENSION)
+}
+
+/// Incarca un singur plugin: biblioteca dinamica dupa extensie, altfel executabil
+pub fn load_plugin(path: &Path) -> Result<Box<dyn Command>, PluginError> {
+    if is_library(path) {
+        Ok(Box::new(LibraryCommand::load(path)?))
+    } else {
+        Ok(Box::new(ProcessCommand::load(path)?))
+    }
+}
+
+/// Numele unei comenzi: un singur cuvant, fara ghilimele sau operatori
+fn check_name(path: &Path, name: &str) -> Result<(), PluginError> {
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || "_-.".contains(c)) {
+        let reason = format!("invalid command name '{}'", name);
+        return Err(PluginError::Invalid { path: path.to_path_buf(), reason });
+    }
+    Ok(())
+}
+
+// --- Plugin-uri din biblioteci dinamice ---
+
+struct LibraryCommand {
+    name: &'static str,
+    description: &'static str,
+    exec: ExecFn,
+    /// Tinuta deschisa cat timp `exec` poate fi apelata
+    _library: Library,
+}
+
+impl LibraryCommand {
+    fn load(path: &Path) -> Result<Self, PluginError> {
+        let library_error = |error| PluginError::Library { path: path.to_path_buf(), error };
+        let invalid = |reason: &str| PluginError::Invalid { path: path.to_path_buf(), reason: reason.to_string() };
+
+        // SAFETY: incarcarea ruleaza constructorii bibliotecii; plugin-urile sunt de incredere
+        // prin faptul ca au fost puse in directorul de plugin-uri
+        let library = unsafe { Library::new(path) }.map_err(library_error)?;
+        // SAFETY: semnaturile simbolurilor sunt cele documentate mai sus; versiunea se
+        // verifica inainte ca `PluginDeclaration` sa fie citita
+        let declaration = unsafe {
+            let version = library.get::<unsafe extern "C" fn() -> u32>(b"laborator_plugin_abi_version\0");
+            let found = version.map_err(library_error)?();
+            if found != PLUGIN_ABI_VERSION {
+                return Err(PluginError::AbiMismatch { path: path.to_path_buf(), found });
+            }
+            let declare = library.get::<unsafe extern "C" fn() -> PluginDeclaration>(b"laborator_plugin_declare\0");
+            declare.map_err(library_error)?()
+        };
+        if declaration.abi_version != PLUGIN_ABI_VERSION {
+            return Err(PluginError::AbiMismatch { path: path.to_path_buf(), found: declaration.abi_version });
+        }
+        if declaration.name.is_null() {
+            return Err(invalid("missing command name"));
+        }
+
+        // SAFETY: pointerii nenuli din declaratie indica siruri terminate cu NUL
+        let text = |ptr: *const c_char| unsafe { CStr::from_ptr(ptr) }.to_str().map(String::from);
+        let name = text(declaration.name).map_err(|_| invalid("command name is not UTF-8"))?;
+        let description = match declaration.description.is_null() {
+            true => String::new(),
+            false => text(declaration.description).map_err(|_| invalid("description is not UTF-8"))?,
+        };
+        check_name(path, &name)?;
+
+        Ok(LibraryCommand {
+            name: Box::leak(name.into_boxed_str()),
+            description: Box::leak(description.into_boxed_str()),
+            exec: declaration.exec,
+            _library: library,
+        })
+    }
+}
+
+/// Strange liniile emise de plugin in `Vec<String>`-ul dat ca `context`
+unsafe extern "C" fn collect_line(context: *mut c_void, line: *const c_char) {
+    if context.is_null() || line.is_null() {
+        return;
+    }
+    // SAFETY: `context` este `&mut Vec<String>` din `LibraryCommand::exec`, iar `line` un sir terminat cu NUL
+    let lines = unsafe { &mut *context.cast::<Vec<String>>() };
+    lines.push(unsafe { CStr::from_ptr(line) }.to_string_lossy().into_owned());
+}
+
+/// Siruri C pentru un apel; `pointers()` ramane valid cat timp exista `self`
+struct CStrings(Vec<CString>);
+
+impl CStrings {
+    /// Un NUL in mijlocul unui cuvant ar taia sirul, deci il inlocuim
+    fn new(words: &[String]) -> Self {
+        CStrings(words.iter().map(|word| CString::new(word.replace('\0', "")).unwrap_or_default()).collect())
+    }
+
+    fn pointers(&self) -> Vec<*const c_char> {
+        self.0.iter().map(|word| word.as_ptr()).collect()
+    }
+}
+
+impl Command for LibraryCommand {
+    fn get_name(&self) -> &'static str { self.name }
+    fn description(&self) -> &'static str { self.description }
+    fn exec(&mut self, args: &Args, input: &[String]) -> CommandResult {
+        let (args, input) = (CStrings::new(args.rest()), CStrings::new(input));
+        let (arg_ptrs, input_ptrs) = (args.pointers(), input.pointers());
+        let mut lines: Vec<String> = Vec::new();
+        // SAFETY: pointerii sunt valizi pe durata apelului; `lines` traieste mai mult decat apelul
+        let status = unsafe {
+            (self.exec)(
+                arg_ptrs.as_ptr(),
+                arg_ptrs.len(),
+                input_ptrs.as_ptr(),
+                input_ptrs.len(),
+                collect_line,
+                (&mut lines as *mut Vec<String>).cast(),
+            )
+        };
+        match status {
+            0 => Ok(lines.into_iter().collect()),
+            _ if lines.is_empty() => Err(CommandError::failed(format!("{} failed with status {}", self.name, status))),
+            _ => Err(CommandError::failed(lines.join("; "))),
+        }
+    }
+}
+
+/// Folosita de `export_plugin!`: converteste argumentele C, ruleaza `run` si emite
+/// rezultatul. O panica in plugin devine o eroare, nu traverseaza granita FFI.
+#[doc(hidden)]
+pub unsafe fn run_exported<F>(
+    args: *const *const c_char,
+    arg_count: usize,
+    input: *const *const c_char,
+    input_count: usize,
+    emit: EmitFn,
+    context: *mut c_void,
+    run: F,
+) -> i32
+where
+    F: Fn(&[String], &[String]) -> Result<Vec<String>, String>,
+{
+    let words = |ptr: *const *const c_char, count: usize| -> Vec<String> {
+        if ptr.is_null() {
+            return Vec::new();
+        }
+        // SAFETY: apelantul trimite `count` siruri terminate cu NUL
+        unsafe { std::slice::from_raw_parts(ptr, count) }
+            .iter()
+            .map(|word| unsafe { CStr::from_ptr(*word) }.to_string_lossy().into_owned())
+            .collect()
+    };
+    let (args, input) = (words(args, arg_count), words(input, input_count));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| run(&args, &input)))
+        .unwrap_or_else(|_| Err("plugin panicked".to_string()));
+    let (status, lines) = match result {
+        Ok(lines) => (0, lines),
+        Err(message) => (1, vec![message]),
+    };
+    for line in CStrings::new(&lines).pointers() {
+        // SAFETY: `emit` si `context` vin de la terminal, `line` este valid pe durata apelului
+        unsafe { emit(context, line) };
+    }
+    status
+}
+
+/// Exporta simbolurile unui plugin scris in Rust, compilat ca `cdylib`:
+///
+/// ```ignore
+/// laborator_traits::export_plugin!("upper", "Print the arguments in upper case", |args, _input| {
+///     Ok(vec![args.join(" ").to_uppercase()])
+/// });
+/// ```
+///
+/// Functia primeste argumentele si liniile de intrare (`&[String]`) si intoarce liniile
+/// de afisat sau un mesaj de eroare.
+#[macro_export]
+macro_rules! export_plugin {
+    ($name:literal, $description:literal, $run:expr) => {
+        #[no_mangle]
+        pub extern "C" fn laborator_plugin_abi_version() -> u32 {
+            $crate::plugin::PLUGIN_ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn laborator_plugin_declare() -> $crate::plugin::PluginDeclaration {
+            unsafe extern "C" fn exec(
+                args: *const *const ::std::ffi::c_char,
+                arg_count: usize,
+                input: *const *const ::std::ffi::c_char,
+                input_count: usize,
+                emit: $crate::plugin::EmitFn,
+                context: *mut ::std::ffi::c_void,
+            ) -> i32 {
+                $crate::plugin::run_exported(args, arg_count, input, input_count, emit, context, $run)
+            }
+            $crate::plugin::PluginDeclaration {
+                abi_version: $crate::plugin::PLUGIN_ABI_VERSION,
+                name: concat!($name, "\0").as_ptr().cast(),
+                description: concat!($description, "\0").as_ptr().cast(),
+                exec,
+            }
+        }
+    };
+}
+
+// --- Plugin-uri executabile (JSON pe stdin/stdout) ---
+
+#[derive(Serialize)]
+#[serde(tag = "request", rename_all = "lowercase")]
+enum Request<'a> {
+    Describe { abi_version: u32 },
+    Exec { abi_version: u32, args: &'a [String], input: &'a [String] },
+}
+
+#[derive(Deserialize)]
+struct Description {
+    abi_version: u32,
+    name: String,
+    #[serde(default)]
+    description: String,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    status: i32,
+    #[serde(default)]
+    output: Vec<String>,
+    error: Option<String>,
+}
+
+struct ProcessCommand {
+    name: &'static str,
+    description: &'static str,
+    path: PathBuf,
+}
+
+/// Porneste `path`, ii trimite cererea pe o linie si citeste raspunsul JSON de pe stdout
+fn call_process<T: for<'de> Deserialize<'de>>(path: &Path, request: &Request) -> Result<T, String> {
+    let mut child = Process::new(path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| format!("could not start: {}", e))?;
+    let mut message = serde_json::to_vec(request).map_err(|e| e.to_string())?;
+    message.push(b'\n');
+    if let Some(mut stdin) = child.stdin.take() {
+        // Un plugin care nu citeste cererea inchide pipe-ul; raspunsul decide
+        let _ = stdin.write_all(&message);
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    serde_json::from_slice(&output.stdout).map_err(|e| match output.status.success() {
+        true => format!("invalid response: {}", e),
+        false => format!("exited with {} without a valid response", output.status),
+    })
+}
+
+impl ProcessCommand {
+    fn load(path: &Path) -> Result<Self, PluginError> {
+        let invalid = |reason: String| PluginError::Invalid { path: path.to_path_buf(), reason };
+        let request = Request::Describe { abi_version: PLUGIN_ABI_VERSION };
+        let description: Description = call_process(path, &request).map_err(|e| invalid(format!("describe: {}", e)))?;
+        if description.abi_version != PLUGIN_ABI_VERSION {
+            return Err(PluginError::AbiMismatch { path: path.to_path_buf(), found: description.abi_version });
+        }
+        check_name(path, &description.name)?;
+        Ok(ProcessCommand {
+            name: Box::leak(description.name.into_boxed_str()),
+            description: Box::leak(description.description.into_boxed_str()),
+            path: path.to_path_buf(),
+        })
+    }
+}
+
+impl Command for ProcessCommand {
+    fn get_name(&self) -> &'static str { self.name }
+    fn description(&self) -> &'static str { self.description }
+    fn exec(&mut self, args: &Args, input: &[String]) -> CommandResult {
+        let request = Request::Exec { abi_version: PLUGIN_ABI_VERSION, args: args.rest(), input };
+        let response: Response =
+            call_process(&self.path, &request).map_err(|e| CommandError::failed(format!("{}: {}", self.name, e)))?;
+        match response {
+            Response { status: 0, output, .. } => Ok(Output::from_iter(output)),
+            Response { error: Some(error), .. } => Err(CommandError::failed(error)),
+            Response { status, .. } => {
+                Err(CommandError::failed(format!("{} failed with status {}", self.name, status)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `examples/upper_plugin.rs`, compilat de `cargo test` langa executabilul testelor
+    fn example_library() -> PathBuf {
+        let exe = std::env::current_exe().unwrap();
+        let dir = exe.parent().and_then(Path::parent).unwrap().join("examples");
+        let file = format!("{}upper_plugin.{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_EXTENSION);
+        dir.join(file)
+    }
+
+    fn plugin_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("laborator-plugins-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn output_of(terminal: &mut Terminal, script: &str) -> String {
+        let mut out = Vec::new();
+        terminal.run(script.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn loads_shared_library_plugins() {
+        let dir = plugin_dir("library");
+        fs::copy(example_library(), dir.join(example_library().file_name().unwrap())).unwrap();
+        fs::write(dir.join("README.txt"), "not a plugin").unwrap();
+
+        let mut terminal = Terminal::new();
+        terminal.register(Box::new(crate::HelloCommand));
+        assert!(register_plugins(&mut terminal, &dir).is_empty());
+        assert_eq!(terminal.command_names().collect::<Vec<_>>(), ["hello", "upper"]);
+        assert_eq!(
+            output_of(&mut terminal, "upper dear team\nhello you | upper\nupper\nhelp upper\n"),
+            "DEAR TEAM\nHELLO, YOU!\nError: nothing to convert\n\
+             upper - Print the arguments, or the piped lines, in upper case\nUsage: upper [args...]\n"
+        );
+
+        // A doua incarcare a aceluiasi plugin intra in conflict de nume
+        let errors = register_plugins(&mut terminal, &dir);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().ends_with("a command named 'upper' is already registered"), "{}", errors[0]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_library_reports_load_error() {
+        let dir = plugin_dir("broken");
+        let path = dir.join(format!("broken.{}", std::env::consts::DLL_EXTENSION));
+        fs::write(&path, "not a library").unwrap();
+        let error = load_plugin(&path).err().unwrap();
+        assert!(matches!(error, PluginError::Library { .. }));
+        assert!(error.to_string().starts_with(&format!("{}: could not load library: ", path.display())));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_directory_has_no_plugins() {
+        let mut terminal = Terminal::new();
+        assert!(register_plugins(&mut terminal, Path::new("/nonexistent/plugins")).is_empty());
+    }
+
+    #[cfg(unix)]
+    fn write_script(dir: &Path, name: &str, body: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        fs::write(&path, format!("#!/bin/sh\nread request\n{}", body)).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn loads_executable_plugins() {
+        let dir = plugin_dir("process");
+        write_script(
+            &dir,
+            "echo",
+            r#"case "$request" in
+  *describe*) echo '{"abi_version":1,"name":"echo-json","description":"Echo the request"}' ;;
+  *'"fail"'*) echo '{"status":3,"error":"asked to fail"}' ;;
+  *) printf '{"status":0,"output":[%s]}' "$(echo "$request" | sed 's/"/\\"/g; s/.*/"&"/')" ;;
+esac
+"#,
+        );
+        write_script(&dir, "future", r#"echo '{"abi_version":2,"name":"future"}'"#);
+        write_script(&dir, "silent", "exit 4");
+
+        let mut terminal = Terminal::new();
+        terminal.register(Box::new(crate::PingCommand));
+        let errors: Vec<String> = register_plugins(&mut terminal, &dir).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            [
+                format!(
+                    "{}: built for plugin ABI version 2, but this terminal supports version 1",
+                    dir.join("future").display()
+                ),
+                format!(
+                    "{}: invalid plugin: describe: exited with exit status: 4 without a valid response",
+                    dir.join("silent").display()
+                ),
+            ]
+        );
+        assert_eq!(
+            output_of(&mut terminal, "echo-json a 'b c'\nping | echo-json\necho-json fail\n"),
+            "{\"request\":\"exec\",\"abi_version\":1,\"args\":[\"a\",\"b c\"],\"input\":[]}\n\
+             {\"request\":\"exec\",\"abi_version\":1,\"args\":[],\"input\":[\"pong!\"]}\n\
+             Error: asked to fail\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,304 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+// --- Scripturi: variabile si structuri de control ---
+//
+// Un script este o lista de linii de comanda, plus blocuri pe mai multe linii:
+//
+//     if [comanda]            for <nume> in <cuvinte...>
+//         ...                     ...
+//     else                    end
+//         ...
+//     end
+//
+// `if` fara comanda decide dupa codul de iesire al comenzii precedente. `$nume` si
+// `${nume}` se inlocuiesc cu valoarea variabilei (setata cu `set`), iar `$?` cu codul
+// de iesire al comenzii precedente; expandarea se face separat pentru fiecare pas al
+// liniei (despartit de `;`, `&&`, `||`), chiar inainte ca pasul sa ruleze.
+
+/// O linie de script sau un bloc
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Line(String),
+    If { condition: Option<String>, then_branch: Vec<Node>, else_branch: Vec<Node> },
+    /// `words` ramane neexpandat: variabilele se evalueaza cand ruleaza bucla
+    For { variable: String, words: String, body: Vec<Node> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    /// `else` sau `end` fara bloc deschis
+    Unexpected(&'static str),
+    /// Blocul deschis de `if`/`for` nu are `end`
+    MissingEnd(&'static str),
+    /// Text dupa `else` sau `end`
+    TrailingText(&'static str),
+    InvalidFor,
+}
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Unexpected("else") => write!(f, "'else' without 'if'"),
+            ScriptError::Unexpected(keyword) => write!(f, "'{}' without 'if' or 'for'", keyword),
+            ScriptError::MissingEnd(keyword) => write!(f, "missing 'end' for '{}'", keyword),
+            ScriptError::TrailingText(keyword) => write!(f, "'{}' must be alone on its line", keyword),
+            ScriptError::InvalidFor => write!(f, "usage: for <name> in <words...>"),
+        }
+    }
+}
+
+impl Error for ScriptError {}
+
+/// Primul cuvant al liniei si restul ei
+fn keyword(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    let (first, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    (first, rest.trim())
+}
+
+/// `true` daca linia incepe cu `if`, `for`, `else` sau `end`
+pub fn is_block_line(line: &str) -> bool {
+    matches!(keyword(line).0, "if" | "for" | "else" | "end")
+}
+
+/// Cu cat schimba linia adancimea blocurilor: `+1` pentru `if`/`for`, `-1` pentru `end`
+pub fn depth_change(line: &str) -> i32 {
+    match keyword(line).0 {
+        "if" | "for" => 1,
+        "end" => -1,
+        _ => 0,
+    }
+}
+
+/// Transforma liniile unui script (fara continuari de linie) in noduri
+pub fn parse_script(lines: &[String]) -> Result<Vec<Node>, ScriptError> {
+    let mut lines = lines.iter();
+    match parse_nodes(&mut lines)? {
+        (nodes, None) => Ok(nodes),
+        (_, Some(terminator)) => Err(ScriptError::Unexpected(terminator)),
+    }
+}
+
+/// Citeste noduri pana la `else`, `end` (intors ca terminator) sau finalul liniilor
+fn parse_nodes<'a, I>(lines: &mut I) -> Result<(Vec<Node>, Option<&'static str>), ScriptError>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let mut nodes = Vec::new();
+    while let Some(line) = lines.next() {
+        let node = match keyword(line) {
+            ("else", rest) if !rest.is_empty() && !rest.starts_with('#') => {
+                return Err(ScriptError::TrailingText("else"))
+            }
+            ("end", rest) if !rest.is_empty() && !rest.starts_with('#') => return Err(ScriptError::TrailingText("end")),
+            ("else", _) => return Ok((nodes, Some("else"))),
+            ("end", _) => return Ok((nodes, Some("end"))),
+            ("if", condition) => {
+                let condition = (!condition.is_empty()).then(|| condition.to_string());
+                let (then_branch, terminator) = parse_nodes(lines)?;
+                let else_branch = match terminator {
+                    Some("else") => match parse_nodes(lines)? {
+                        (nodes, Some("end")) => nodes,
+                        (_, Some(other)) => return Err(ScriptError::Unexpected(other)),
+                        (_, None) => return Err(ScriptError::MissingEnd("if")),
+                    },
+                    Some(_) => Vec::new(),
+                    None => return Err(ScriptError::MissingEnd("if")),
+                };
+                Node::If { condition, then_branch, else_branch }
+            }
+            ("for", header) => {
+                let (variable, words) = parse_for(header)?;
+                let body = match parse_nodes(lines)? {
+                    (body, Some("end")) => body,
+                    (_, Some(other)) => return Err(ScriptError::Unexpected(other)),
+                    (_, None) => return Err(ScriptError::MissingEnd("for")),
+                };
+                Node::For { variable, words, body }
+            }
+            _ => Node::Line(line.clone()),
+        };
+        nodes.push(node);
+    }
+    Ok((nodes, None))
+}
+
+/// `x in a b c` -> (`x`, `a b c`)
+fn parse_for(header: &str) -> Result<(String, String), ScriptError> {
+    let (variable, rest) = keyword(header);
+    match keyword(rest) {
+        ("in", words) if is_identifier(variable) => Ok((variable.to_string(), words.to_string())),
+        _ => Err(ScriptError::InvalidFor),
+    }
+}
+
+/// Nume de variabila: litere, cifre si `_`, fara cifra la inceput
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// --- Expandarea `$...` ---
+
+/// Inlocuieste `$nume`, `${nume}` si `$` urmat de unul din `0-9@#?` cu cuvintele date
+/// de `lookup`; daca `lookup` intoarce `None`, textul ramane neschimbat.
+///
+/// In afara ghilimelelor fiecare cuvant este pus intre ghilimele simple, ca sa ramana
+/// un singur cuvant la tokenizare; intre ghilimele duble cuvintele sunt unite prin
+/// spatiu si inserate ca text. Intre ghilimele simple si dupa `\` nu se expandeaza nimic.
+pub fn expand<F>(text: &str, lookup: F) -> String
+where
+    F: Fn(&str) -> Option<Vec<String>>,
+{
+    let mut result = String::new();
+    let mut rest = text;
+    let mut quote = None;
+
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match (c, quote) {
+            ('\\', q) if q != Some('\'') => {
+                result.push(c);
+                if let Some(next) = rest.chars().next() {
+                    result.push(next);
+                    rest = &rest[next.len_utf8()..];
+                }
+                continue;
+            }
+            ('\'' | '"', None) => quote = Some(c),
+            (_, Some(q)) if c == q => quote = None,
+            ('$', q) if q != Some('\'') => {
+                if let Some((name, after)) = variable_name(rest) {
+                    if let Some(words) = lookup(name) {
+                        match q {
+                            Some(_) => result += &escape_in_double_quotes(&words.join(" ")),
+                            None => result += &words.iter().map(|word| shell_quote(word)).collect::<Vec<_>>().join(" "),
+                        }
+                        rest = after;
+                        continue;
+                    }
+                }
+            }
+            _ => {}
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Numele de dupa `$` si textul ramas dupa el
+fn variable_name(text: &str) -> Option<(&str, &str)> {
+    let first = text.chars().next()?;
+    if first == '{' {
+        let end = text.find('}')?;
+        let name = &text[1..end];
+        return is_identifier(name).then(|| (name, &text[end + 1..]));
+    }
+    if first.is_ascii_digit() || "@#?".contains(first) {
+        return Some((&text[..1], &text[1..]));
+    }
+    let end = text.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(text.len());
+    let name = &text[..end];
+    is_identifier(name).then(|| (name, &text[end..]))
+}
+
+/// `it's` -> `'it'\''s'`
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn escape_in_double_quotes(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('$', "\\$")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::{tokenize, Token};
+
+    fn lines(script: &str) -> Vec<String> {
+        script.lines().map(String::from).collect()
+    }
+
+    fn line(text: &str) -> Node {
+        Node::Line(text.to_string())
+    }
+
+    fn words(text: &str) -> Vec<String> {
+        tokenize(text)
+            .unwrap()
+            .into_iter()
+            .map(|token| match token {
+                Token::Word(word) => word,
+                Token::Op(op) => op.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_nested_blocks() {
+        let script = "ping\nif\n  for x in a $y\n    hello $x\n  end\nelse # failed\n  times\nend\nif ping\nend";
+        assert_eq!(
+            parse_script(&lines(script)).unwrap(),
+            [
+                line("ping"),
+                Node::If {
+                    condition: None,
+                    then_branch: vec![Node::For {
+                        variable: "x".into(),
+                        words: "a $y".into(),
+                        body: vec![line("    hello $x")],
+                    }],
+                    else_branch: vec![line("  times")],
+                },
+                Node::If { condition: Some("ping".into()), then_branch: vec![], else_branch: vec![] },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_unbalanced_blocks() {
+        let err = |script: &str| parse_script(&lines(script)).unwrap_err().to_string();
+        assert_eq!(err("if\nping"), "missing 'end' for 'if'");
+        assert_eq!(err("for x in a\nif\nend"), "missing 'end' for 'for'");
+        assert_eq!(err("ping\nend"), "'end' without 'if' or 'for'");
+        assert_eq!(err("else"), "'else' without 'if'");
+        assert_eq!(err("for x in a\nelse\nend"), "'else' without 'if'");
+        assert_eq!(err("if\nend now"), "'end' must be alone on its line");
+        assert_eq!(err("for 1x in a\nend"), "usage: for <name> in <words...>");
+        assert_eq!(err("for x a\nend"), "usage: for <name> in <words...>");
+    }
+
+    #[test]
+    fn tracks_block_depth() {
+        assert!(is_block_line("  for x in a"));
+        assert!(is_block_line("end"));
+        assert!(!is_block_line("format"));
+        let depth: i32 = ["if", "for x in a", "end", "ping", "end"].iter().map(|line| depth_change(line)).sum();
+        assert_eq!(depth, 0);
+    }
+
+    #[test]
+    fn expands_variables() {
+        let lookup = |name: &str| match name {
+            "langs" => Some(vec!["rust".to_string(), "go lang".to_string()]),
+            "?" => Some(vec!["0".to_string()]),
+            "empty" => Some(vec![]),
+            _ => None,
+        };
+        assert_eq!(words(&expand("for x in $langs", lookup)), ["for", "x", "in", "rust", "go lang"]);
+        assert_eq!(words(&expand("hello \"${langs}!\" $? $empty", lookup)), ["hello", "rust go lang!", "0"]);
+        assert_eq!(
+            words(&expand("hello '$langs' \\$langs $other $ ${1x}", lookup)),
+            ["hello", "$langs", "$langs", "$other", "$", "${1x}"]
+        );
+    }
+
+    #[test]
+    fn expanded_text_is_not_expanded_again() {
+        let lookup = |name: &str| (name == "x").then(|| vec!["$x \"q\"".to_string()]);
+        assert_eq!(words(&expand("a $x \"$x\"", lookup)), ["a", "$x \"q\"", "$x \"q\""]);
+    }
+}
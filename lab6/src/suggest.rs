@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+// --- Sugestii "Did you mean" ---
+//
+// Candidatii sunt ordonati dupa distanta Damerau–Levenshtein (insertie, stergere,
+// inlocuire si transpozitia a doua caractere vecine costa cate 1), fara a tine
+// cont de majuscule. Se pastreaza doar cei suficient de apropiati.
+
+/// Cate sugestii se afiseaza cel mult
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Distanta Damerau–Levenshtein (varianta completa, nu "optimal string alignment":
+/// `ca` -> `abc` costa 2, nu 3)
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+    let infinity = n + m;
+
+    // d[i + 1][j + 1] = distanta dintre a[..i] si b[..j]; randul/coloana 0 sunt santinele
+    let mut d = vec![vec![0; m + 2]; n + 2];
+    d[0][0] = infinity;
+    for i in 0..=n {
+        d[i + 1][0] = infinity;
+        d[i + 1][1] = i;
+    }
+    for j in 0..=m {
+        d[0][j + 1] = infinity;
+        d[1][j + 1] = j;
+    }
+
+    // Ultimul rand din `a` in care a aparut fiecare caracter
+    let mut last_row: HashMap<char, usize> = HashMap::new();
+    for i in 1..=n {
+        let mut last_match_col = 0;
+        for j in 1..=m {
+            let i1 = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let j1 = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            d[i + 1][j + 1] = (d[i][j] + cost)
+                .min(d[i + 1][j] + 1)
+                .min(d[i][j + 1] + 1)
+                .min(d[i1][j1] + (i - i1 - 1) + 1 + (j - j1 - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[n + 1][m + 1]
+}
+
+/// Distanta maxima acceptata: o greseala la cuvinte scurte, una la fiecare 3 caractere altfel
+fn threshold(word: &str) -> usize {
+    (word.chars().count() / 3).max(1)
+}
+
+/// Cei mai apropiati candidati de `word` (cel mult `MAX_SUGGESTIONS`), in ordinea
+/// distantei si apoi alfabetic; `word` insusi nu este sugerat
+pub fn suggestions<'a, I>(word: &str, candidates: I) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let lower = word.to_lowercase();
+    let mut ranked: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != word)
+        .map(|candidate| (damerau_levenshtein(&lower, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= threshold(word))
+        .collect();
+    ranked.sort();
+    ranked.dedup();
+    ranked.into_iter().take(MAX_SUGGESTIONS).map(|(_, candidate)| candidate).collect()
+}
+
+/// `'a'`, `'a' or 'b'`, `'a', 'b' or 'c'`
+pub fn quote_list(words: &[&str]) -> String {
+    let quoted: Vec<String> = words.iter().map(|word| format!("'{}'", word)).collect();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance() {
+        assert_eq!(damerau_levenshtein("", ""), 0);
+        assert_eq!(damerau_levenshtein("ping", ""), 4);
+        assert_eq!(damerau_levenshtein("pnig", "ping"), 1);
+        assert_eq!(damerau_levenshtein("bkk", "bk"), 1);
+        assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+        // transpozitie urmata de insertie intre caracterele transpuse
+        assert_eq!(damerau_levenshtein("ca", "abc"), 2);
+        assert_eq!(damerau_levenshtein("serch", "search"), 1);
+    }
+
+    #[test]
+    fn ranks_and_filters_candidates() {
+        let names = ["ping", "count", "times", "hello", "help", "bk", "stop"];
+        assert_eq!(suggestions("pnig", names), ["ping"]);
+        assert_eq!(suggestions("bkk", names), ["bk"]);
+        assert_eq!(suggestions("HELLO", names), ["hello"]);
+        assert_eq!(suggestions("helo", names), ["hello", "help"]);
+        assert!(suggestions("xyz", names).is_empty());
+        assert!(suggestions("ping", names).is_empty());
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(quote_list(&[]), "");
+        assert_eq!(quote_list(&["a"]), "'a'");
+        assert_eq!(quote_list(&["a", "b", "c"]), "'a', 'b' or 'c'");
+    }
+}
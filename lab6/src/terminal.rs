@@ -0,0 +1,1136 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::command::{Command, CommandError, CommandResult, Output};
+use crate::completion::Completions;
+use crate::definitions::{parse_statement, substitute_args, Definitions, Statement};
+use crate::pipeline::{parse_line, split_steps, Condition, Pipeline};
+use crate::script::{depth_change, expand, is_block_line, is_identifier, parse_script, Node};
+use crate::suggest::suggestions;
+use crate::tokenizer::{needs_continuation, tokenize, Token};
+
+// --- Structura Terminal ---
+
+/// Ce face bucla de citire dupa o linie procesata
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Flow {
+    Continue,
+    Stop,
+}
+
+/// Registrul de comenzi si bucla care le executa.
+///
+/// Dupa fiecare linie, `last_status()` este 0 la succes sau `CommandError::exit_code()`.
+#[derive(Default)]
+pub struct Terminal {
+    commands: Vec<Box<dyn Command>>,
+    last_status: i32,
+    stop_on_error: bool,
+    autocorrect: bool,
+    definitions: Definitions,
+    /// Alias-urile si macro-urile aflate in curs de expandare, pentru detectarea recursivitatii
+    expanding: Vec<String>,
+    /// Variabilele setate cu `set`, fiecare o lista de cuvinte
+    variables: BTreeMap<String, Vec<String>>,
+    /// Liniile unui bloc `if`/`for` inca neterminat
+    pending: Vec<String>,
+    /// Fisierele rulate cu `source` (sau `run_file`) aflate in curs de executie
+    sourcing: Vec<PathBuf>,
+}
+
+/// Comenzile interne, tratate direct de `Terminal`; nu pot fi redefinite
+pub(crate) const BUILTINS: [&str; 12] =
+    ["help", "stop", "alias", "define", "unalias", "set", "unset", "source", "if", "else", "end", "for"];
+
+impl Terminal {
+    pub fn new() -> Self {
+        Terminal::default()
+    }
+
+    pub fn register(&mut self, command: Box<dyn Command>) {
+        self.commands.push(command);
+    }
+
+    /// Opreste `run`/`run_interactive` la prima comanda esuata (pentru scripturi)
+    pub fn set_stop_on_error(&mut self, stop: bool) {
+        self.stop_on_error = stop;
+    }
+
+    /// Ruleaza automat comanda sugerata cand o comanda necunoscuta are o singura
+    /// sugestie (ex: `pnig` -> `ping`), dupa ce afiseaza corectura
+    pub fn set_autocorrect(&mut self, autocorrect: bool) {
+        self.autocorrect = autocorrect;
+    }
+
+    /// Codul de iesire al ultimei linii executate (0 = succes)
+    pub fn last_status(&self) -> i32 {
+        self.last_status
+    }
+
+    /// Numele comenzilor inregistrate, in ordinea inregistrarii
+    pub fn command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.commands.iter().map(|cmd| cmd.get_name())
+    }
+
+    /// `true` daca `name` este o comanda inregistrata sau o comanda interna (`help`, `alias`, ...)
+    pub fn has_command(&self, name: &str) -> bool {
+        self.is_command(name) || BUILTINS.contains(&name)
+    }
+
+    /// Alias-urile si macro-urile definite cu `alias` si `define`
+    pub fn definitions(&self) -> &Definitions {
+        &self.definitions
+    }
+
+    /// Valoarea variabilei `name`, setata cu `set`
+    pub fn variable(&self, name: &str) -> Option<&[String]> {
+        self.variables.get(name).map(Vec::as_slice)
+    }
+
+    /// `true` cat timp se citesc liniile unui bloc `if`/`for`, pana la `end`
+    pub fn in_block(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Tabela pentru completarea cu TAB: comenzile, subcomenzile lor, comenzile interne,
+    /// alias-urile si macro-urile
+    pub fn completions(&self) -> Completions {
+        let mut completions = Completions::new();
+        for cmd in &self.commands {
+            completions.insert(cmd.get_name(), cmd.signature().subcommand_names().map(String::from).collect());
+        }
+        for name in BUILTINS.into_iter().chain(self.definition_names()) {
+            completions.insert(name, Vec::new());
+        }
+        completions
+    }
+
+    fn definition_names(&self) -> impl Iterator<Item = &str> + '_ {
+        let aliases = self.definitions.aliases().map(|(name, _)| name);
+        aliases.chain(self.definitions.macros().map(|(name, _)| name))
+    }
+
+    /// Incarca alias-urile si macro-urile dintr-un fisier de configurare: doar linii
+    /// `alias`/`define`, comentarii si linii goale. Intoarce numarul de definitii;
+    /// la prima linie gresita intoarce `InvalidData` cu numarul liniei.
+    pub fn load_definitions<R: BufRead>(&mut self, input: R) -> io::Result<usize> {
+        let mut count = 0;
+        for (index, line) in input.lines().enumerate() {
+            let line = line?;
+            let invalid = |message: String| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", index + 1, message))
+            };
+            match parse_statement(&line) {
+                Some(Ok(statement)) => {
+                    self.define(statement);
+                    count += 1;
+                }
+                Some(Err(e)) => return Err(invalid(e.to_string())),
+                None if matches!(tokenize(&line), Ok(tokens) if tokens.is_empty()) => {}
+                None => return Err(invalid("expected an 'alias' or 'define' line".to_string())),
+            }
+        }
+        Ok(count)
+    }
+
+    fn define(&mut self, statement: Statement) {
+        match statement {
+            Statement::Alias { name, text } => self.definitions.set_alias(name, text),
+            Statement::Define { name, body } => self.definitions.set_macro(name, body),
+        }
+    }
+
+    /// Executa toate liniile din `input` (ex: `commands.txt`), pana la `stop` sau EOF
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, out: W) -> io::Result<()> {
+        self.run_loop(input, out, false)
+    }
+
+    /// Ca `run`, dar afiseaza promptul `> ` inaintea fiecarei linii (`. ` in interiorul unui bloc)
+    pub fn run_interactive<R: BufRead, W: Write>(&mut self, input: R, out: W) -> io::Result<()> {
+        self.run_loop(input, out, true)
+    }
+
+    /// Ruleaza scriptul din `path`, ca `source <path>`: daca fisierul nu poate fi deschis
+    /// eroarea se afiseaza in `out`, iar `last_status()` devine 1
+    pub fn run_file(&mut self, path: &Path, out: &mut dyn Write) -> io::Result<()> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) => {
+                let error = CommandError::failed(format!("could not open '{}': {}", path.display(), e));
+                return self.report(Err(error), out);
+            }
+        };
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if let Some(start) = self.sourcing.iter().position(|running| *running == canonical) {
+            let chain: Vec<String> =
+                self.sourcing[start..].iter().chain([&canonical]).map(|path| path.display().to_string()).collect();
+            let error = CommandError::failed(format!("recursive source: {}", chain.join(" -> ")));
+            return self.report(Err(error), out);
+        }
+
+        self.sourcing.push(canonical);
+        let result = self.run_loop(BufReader::new(file), out, false);
+        self.sourcing.pop();
+        result
+    }
+
+    fn run_loop<R: BufRead, W: Write>(&mut self, mut input: R, mut out: W, interactive: bool) -> io::Result<()> {
+        let mut buffer = String::new();
+        loop {
+            if interactive {
+                write!(out, "{}", if self.in_block() { ". " } else { "> " })?;
+                out.flush()?;
+            }
+            buffer.clear();
+            if input.read_line(&mut buffer)? == 0 {
+                return self.finish(&mut out); // EOF
+            }
+            // `\` la final de linie: comanda continua pe linia urmatoare
+            while needs_continuation(&buffer) {
+                if input.read_line(&mut buffer)? == 0 {
+                    break;
+                }
+            }
+            if self.process_line(&buffer, &mut out)? == Flow::Stop {
+                return Ok(());
+            }
+            // O linie adaugata la un bloc neterminat nu a rulat inca nimic
+            if self.stop_on_error && self.last_status != 0 && !self.in_block() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Sfarsitul intrarii: un bloc fara `end` se raporteaza ca eroare de sintaxa
+    pub fn finish(&mut self, out: &mut dyn Write) -> io::Result<()> {
+        let lines = std::mem::take(&mut self.pending);
+        match parse_script(&lines) {
+            Err(e) if !lines.is_empty() => self.report(Err(CommandError::Syntax(e.to_string())), out),
+            _ => Ok(()),
+        }
+    }
+
+    /// Executa o linie (cu `;`, `&&`, `||` si `|`, vezi `pipeline`) si afiseaza rezultatul;
+    /// `stop` opreste bucla. Liniile unui bloc `if`/`for` se aduna pana la `end`, apoi
+    /// blocul ruleaza tot odata. Intoarce eroare doar daca scrierea in `out` esueaza.
+    pub fn process_line(&mut self, line: &str, out: &mut dyn Write) -> io::Result<Flow> {
+        if !self.in_block() && !is_block_line(line) {
+            return self.execute_line(line, out);
+        }
+        self.pending.push(line.to_string());
+        if self.pending.iter().map(|line| depth_change(line)).sum::<i32>() > 0 {
+            return Ok(Flow::Continue);
+        }
+        let lines = std::mem::take(&mut self.pending);
+        match parse_script(&lines) {
+            Ok(nodes) => self.run_nodes(&nodes, out),
+            Err(e) => {
+                self.report(Err(CommandError::Syntax(e.to_string())), out)?;
+                Ok(Flow::Continue)
+            }
+        }
+    }
+
+    /// Ruleaza nodurile unui bloc; cu `set_stop_on_error`, prima linie esuata opreste tot
+    fn run_nodes(&mut self, nodes: &[Node], out: &mut dyn Write) -> io::Result<Flow> {
+        for node in nodes {
+            let flow = match node {
+                Node::Line(line) => {
+                    let flow = self.execute_line(line, out)?;
+                    if self.stop_on_error && self.last_status != 0 {
+                        return Ok(Flow::Stop);
+                    }
+                    flow
+                }
+                Node::If { condition, then_branch, else_branch } => {
+                    if let Some(condition) = condition {
+                        if self.execute_line(condition, out)? == Flow::Stop {
+                            return Ok(Flow::Stop);
+                        }
+                    }
+                    let branch = if self.last_status == 0 { then_branch } else { else_branch };
+                    // Ca in shell: fara ramura de rulat, `if` reuseste
+                    if branch.is_empty() {
+                        self.last_status = 0;
+                    }
+                    self.run_nodes(branch, out)?
+                }
+                Node::For { variable, words, body } => {
+                    let words = match self.expand_words(words) {
+                        Ok(words) => words,
+                        Err(e) => {
+                            self.report(Err(e), out)?;
+                            continue;
+                        }
+                    };
+                    self.last_status = 0;
+                    let mut flow = Flow::Continue;
+                    for word in words {
+                        self.variables.insert(variable.clone(), vec![word]);
+                        flow = self.run_nodes(body, out)?;
+                        if flow == Flow::Stop {
+                            break;
+                        }
+                    }
+                    flow
+                }
+            };
+            if flow == Flow::Stop {
+                return Ok(Flow::Stop);
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    /// Inlocuieste `$nume`, `${nume}` si `$?` (vezi `script::expand`); o variabila nesetata
+    /// nu da niciun cuvant
+    fn expand_variables(&self, text: &str) -> String {
+        expand(text, |name| match name {
+            "?" => Some(vec![self.last_status.to_string()]),
+            _ if is_identifier(name) => Some(self.variables.get(name).cloned().unwrap_or_default()),
+            _ => None,
+        })
+    }
+
+    /// Cuvintele listei unui `for`, dupa expandarea variabilelor
+    fn expand_words(&self, text: &str) -> Result<Vec<String>, CommandError> {
+        let tokens = tokenize(&self.expand_variables(text)).map_err(|e| CommandError::Syntax(e.to_string()))?;
+        tokens
+            .into_iter()
+            .map(|token| match token {
+                Token::Word(word) => Ok(word),
+                Token::Op(op) => Err(CommandError::Syntax(format!("unexpected '{}' in 'for' words", op))),
+            })
+            .collect()
+    }
+
+    /// O singura linie, fara blocuri: definitie (`alias`/`define`) sau comenzi
+    fn execute_line(&mut self, line: &str, out: &mut dyn Write) -> io::Result<Flow> {
+        // `alias`/`define` ocupa toata linia: corpul unui macro poate contine `;`
+        match parse_statement(line) {
+            Some(Ok(statement)) => {
+                self.define(statement);
+                self.last_status = 0;
+                return Ok(Flow::Continue);
+            }
+            Some(Err(e)) => {
+                self.report(Err(CommandError::Syntax(e.to_string())), out)?;
+                return Ok(Flow::Continue);
+            }
+            None => {}
+        }
+
+        let steps = match split_steps(line) {
+            Ok(steps) => steps,
+            Err(e) => {
+                self.report(Err(CommandError::Syntax(e.to_string())), out)?;
+                return Ok(Flow::Continue);
+            }
+        };
+
+        for (condition, text) in steps {
+            let run = match condition {
+                Condition::Always => true,
+                Condition::OnSuccess => self.last_status == 0,
+                Condition::OnFailure => self.last_status != 0,
+            };
+            if !run {
+                continue;
+            }
+            // Variabilele se expandeaza chiar inainte de pas: `nope; hello $?` vede statusul lui `nope`
+            let pipelines = match parse_line(&self.expand_variables(text)) {
+                Ok(steps) => steps.into_iter().map(|step| step.pipeline),
+                Err(e) => {
+                    self.report(Err(CommandError::Syntax(e.to_string())), out)?;
+                    continue;
+                }
+            };
+            for pipeline in pipelines {
+                if self.run_pipeline(&pipeline, out)? == Flow::Stop {
+                    return Ok(Flow::Stop);
+                }
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    /// Doar iesirea ultimei comenzi se afiseaza; erorile se afiseaza pe loc, iar
+    /// comanda urmatoare primeste atunci o intrare goala. Statusul final este al ultimei comenzi.
+    fn run_pipeline(&mut self, pipeline: &Pipeline, out: &mut dyn Write) -> io::Result<Flow> {
+        let mut input = Vec::new();
+        for (k, command) in pipeline.iter().enumerate() {
+            let mut command = command.clone();
+            if self.autocorrect && !self.is_known(&command[0]) {
+                if let [suggestion] = self.suggest(&command[0]).as_slice() {
+                    writeln!(out, "(running '{}' instead of '{}')", suggestion, command[0])?;
+                    command[0] = suggestion.to_string();
+                }
+            }
+            // Alias-urile expandate aici raman pe stiva doar pe durata acestei comenzi
+            let depth = self.expanding.len();
+            let result = self.expand_alias(command);
+            let result = match result {
+                Ok(command) if self.is_macro_call(&command[0]) || command[0] == "source" => {
+                    let (cmd_name, args) = command.split_first().expect("commands and aliases are never empty");
+                    let last = k + 1 == pipeline.len();
+                    let result = match cmd_name.as_str() {
+                        "source" => self.run_source(args, last, out),
+                        _ => self.run_macro(cmd_name, args, last, out),
+                    };
+                    self.expanding.truncate(depth);
+                    let (flow, output) = result?;
+                    if flow == Flow::Stop {
+                        return Ok(Flow::Stop);
+                    }
+                    input = output;
+                    continue;
+                }
+                Ok(command) if command[0] == "stop" => {
+                    self.expanding.truncate(depth);
+                    return Ok(Flow::Stop);
+                }
+                Ok(command) => self.dispatch_with_input(&command[0], &command[1..], &input),
+                Err(e) => Err(e),
+            };
+            self.expanding.truncate(depth);
+            input = match result {
+                Ok(output) if k + 1 < pipeline.len() => {
+                    self.last_status = 0;
+                    output.into_lines()
+                }
+                result => {
+                    self.report(result, out)?;
+                    Vec::new()
+                }
+            };
+        }
+        Ok(Flow::Continue)
+    }
+
+    // --- Alias-uri si macro-uri ---
+
+    fn is_command(&self, name: &str) -> bool {
+        self.commands.iter().any(|cmd| cmd.get_name() == name)
+    }
+
+    fn is_known(&self, name: &str) -> bool {
+        self.is_command(name)
+            || BUILTINS.contains(&name)
+            || self.definitions.alias(name).is_some()
+            || self.definitions.macro_body(name).is_some()
+    }
+
+    /// Numele care seamana cu `name`: comenzi, comenzi interne, alias-uri si macro-uri
+    fn suggest(&self, name: &str) -> Vec<String> {
+        let mut names: Vec<&str> = self.command_names().chain(BUILTINS).collect();
+        names.extend(self.definition_names());
+        suggestions(name, names).into_iter().map(String::from).collect()
+    }
+
+    /// Eroare daca `name` este deja in curs de expandare (`a -> b -> a`), in afara de cazul
+    /// in care exista o comanda cu acelasi nume (`alias ping=ping -x`), care se ruleaza atunci
+    fn check_recursion(&self, name: &str) -> Result<bool, CommandError> {
+        match self.expanding.iter().position(|expanding| expanding == name) {
+            None => Ok(false),
+            Some(_) if self.is_command(name) => Ok(true),
+            Some(start) => {
+                let chain: Vec<&str> = self.expanding[start..].iter().map(String::as_str).chain([name]).collect();
+                Err(CommandError::failed(format!("recursive definition: {}", chain.join(" -> "))))
+            }
+        }
+    }
+
+    /// Inlocuieste numele comenzii cat timp este un alias: `b rust` -> `bk search rust`.
+    /// Alias-urile expandate sunt puse pe stiva `expanding`.
+    fn expand_alias(&mut self, mut command: Vec<String>) -> Result<Vec<String>, CommandError> {
+        while let Some(text) = self.definitions.alias(&command[0]) {
+            if self.check_recursion(&command[0])? {
+                break;
+            }
+            let tokens = tokenize(text).map_err(|e| CommandError::Syntax(e.to_string()))?;
+            let mut words: Vec<String> = tokens
+                .into_iter()
+                .filter_map(|token| match token {
+                    Token::Word(word) => Some(word),
+                    Token::Op(_) => None,
+                })
+                .collect();
+            words.extend(command.drain(1..));
+            self.expanding.push(std::mem::replace(&mut command, words).swap_remove(0));
+        }
+        if !self.is_macro_call(&command[0]) {
+            self.check_recursion(&command[0])?;
+        }
+        Ok(command)
+    }
+
+    fn is_macro_call(&self, name: &str) -> bool {
+        self.definitions.macro_body(name).is_some() && !self.expanding.iter().any(|expanding| expanding == name)
+    }
+
+    /// Ruleaza corpul macro-ului cu argumentele substituite (vezi `with_output`)
+    fn run_macro(
+        &mut self,
+        name: &str,
+        args: &[String],
+        last: bool,
+        out: &mut dyn Write,
+    ) -> io::Result<(Flow, Vec<String>)> {
+        let line = substitute_args(self.definitions.macro_body(name).unwrap_or_default(), args);
+        self.expanding.push(name.to_string());
+        self.with_output(last, out, |terminal, out| terminal.execute_line(&line, out))
+    }
+
+    /// `source <file>`: ruleaza liniile fisierului; `stop` opreste doar fisierul
+    fn run_source(&mut self, args: &[String], last: bool, out: &mut dyn Write) -> io::Result<(Flow, Vec<String>)> {
+        let [path] = args else {
+            self.report(Err(CommandError::failed("usage: source <file>")), out)?;
+            return Ok((Flow::Continue, Vec::new()));
+        };
+        self.with_output(last, out, |terminal, out| {
+            terminal.run_file(Path::new(path), out)?;
+            Ok(Flow::Continue)
+        })
+    }
+
+    /// Ruleaza `run` cu iesirea in `out` daca este ultima comanda din pipeline; altfel
+    /// iesirea (inclusiv mesajele de eroare) devine intrarea urmatoarei comenzi
+    fn with_output<F>(&mut self, last: bool, out: &mut dyn Write, run: F) -> io::Result<(Flow, Vec<String>)>
+    where
+        F: FnOnce(&mut Self, &mut dyn Write) -> io::Result<Flow>,
+    {
+        if last {
+            return Ok((run(self, out)?, Vec::new()));
+        }
+        let mut captured = Vec::new();
+        let flow = run(self, &mut captured)?;
+        Ok((flow, String::from_utf8_lossy(&captured).lines().map(String::from).collect()))
+    }
+
+    /// Retine codul de iesire si afiseaza rezultatul sau eroarea
+    fn report(&mut self, result: CommandResult, out: &mut dyn Write) -> io::Result<()> {
+        self.last_status = match &result {
+            Ok(_) => 0,
+            Err(e) => e.exit_code(),
+        };
+        match result {
+            Ok(output) => write!(out, "{}", output),
+            Err(e @ CommandError::UnknownCommand { .. }) => writeln!(out, "{}", e),
+            Err(CommandError::InvalidArgs { error, usage }) => {
+                writeln!(out, "Error: {}", error)?;
+                writeln!(out, "Usage: {}", usage)
+            }
+            Err(e) => writeln!(out, "Error: {}", e),
+        }
+    }
+
+    /// Valideaza argumentele si executa comanda `name` (inclusiv `help`), fara a afisa nimic
+    pub fn dispatch(&mut self, name: &str, args: &[String]) -> CommandResult {
+        self.dispatch_with_input(name, args, &[])
+    }
+
+    /// Ca `dispatch`, cu liniile primite printr-un pipeline
+    pub fn dispatch_with_input(&mut self, name: &str, args: &[String], input: &[String]) -> CommandResult {
+        match name {
+            "help" => return self.help(args),
+            "alias" | "define" | "unalias" => return self.definition_builtin(name, args),
+            "set" | "unset" => return self.variable_builtin(name, args),
+            "if" | "else" | "end" | "for" => {
+                return Err(CommandError::failed(format!("'{}' is only allowed at the start of a line", name)))
+            }
+            _ => {}
+        }
+        if let Some(cmd) = self.commands.iter_mut().find(|cmd| cmd.get_name() == name) {
+            let signature = cmd.signature();
+            return match signature.parse(args) {
+                Ok(parsed) => cmd.exec(&parsed, input),
+                Err(error) => Err(CommandError::InvalidArgs { error, usage: signature.usage_for(name, args) }),
+            };
+        }
+
+        Err(CommandError::UnknownCommand { name: name.to_string(), suggestions: self.suggest(name) })
+    }
+
+    /// `alias`/`define` fara argumente listeaza definitiile, `unalias <name>` sterge una;
+    /// definitiile noi sunt tratate de `process_line`, doar la inceput de linie
+    fn definition_builtin(&mut self, name: &str, args: &[String]) -> CommandResult {
+        match (name, args) {
+            ("alias", []) => {
+                Ok(self.definitions.aliases().map(|(name, text)| format!("alias {}={}", name, text)).collect())
+            }
+            ("define", []) => {
+                Ok(self.definitions.macros().map(|(name, body)| format!("define {} = {}", name, body)).collect())
+            }
+            ("unalias", [target]) if self.definitions.remove(target) => Ok(Output::new()),
+            ("unalias", [target]) => Err(CommandError::failed(format!("no alias or macro named '{}'", target))),
+            ("unalias", _) => Err(CommandError::failed("usage: unalias <name>")),
+            _ => Err(CommandError::failed(format!("'{}' must start a line of its own: {} <name>=<text>", name, name))),
+        }
+    }
+
+    /// `set <name> <words...>` seteaza o variabila, `set` le listeaza, `unset <name>` sterge una
+    fn variable_builtin(&mut self, name: &str, args: &[String]) -> CommandResult {
+        match (name, args) {
+            ("set", []) => {
+                Ok(self.variables.iter().map(|(name, words)| format!("{}={}", name, words.join(" "))).collect())
+            }
+            ("set", [variable, words @ ..]) if is_identifier(variable) => {
+                self.variables.insert(variable.clone(), words.to_vec());
+                Ok(Output::new())
+            }
+            ("set", [variable, ..]) => Err(CommandError::failed(format!("invalid variable name '{}'", variable))),
+            ("unset", [variable]) if self.variables.remove(variable).is_some() => Ok(Output::new()),
+            ("unset", [variable]) => Err(CommandError::failed(format!("no variable named '{}'", variable))),
+            _ => Err(CommandError::failed("usage: unset <name>")),
+        }
+    }
+
+    // --- Comanda interna `help` ---
+
+    /// `help` listeaza comenzile; `help <cmd>` si `help <cmd> <subcomanda>` afiseaza detaliile
+    fn help(&self, topic: &[String]) -> CommandResult {
+        let Some(name) = topic.first() else {
+            return Ok(self.help_overview());
+        };
+        if let Some(text) = self.definitions.alias(name) {
+            return Ok(Output::line(format!("{} - alias for '{}'", name, text)));
+        }
+        if let Some(body) = self.definitions.macro_body(name) {
+            return Ok(Output::line(format!("{} - macro for '{}'", name, body)));
+        }
+        let Some(cmd) = self.commands.iter().find(|cmd| cmd.get_name() == name) else {
+            let mut topics: Vec<&str> = self.command_names().collect();
+            topics.extend(self.definition_names());
+            let close = suggestions(name, topics);
+            let hint = match close.first() {
+                Some(close) => format!("did you mean '{}'?", close),
+                None => "type 'help' for a list".to_string(),
+            };
+            return Err(CommandError::failed(format!("no help for unknown command '{}'; {}", name, hint)));
+        };
+
+        let signature = cmd.signature();
+        let (title, description, usage, signature) = match topic.get(1) {
+            None => (name.clone(), cmd.description(), cmd.usage(), &signature),
+            Some(sub) => match signature.find_subcommand(sub) {
+                Some(sub_signature) => {
+                    let title = format!("{} {}", name, sub);
+                    let usage = sub_signature.usage_lines(&title);
+                    (title, sub_signature.description(), usage, sub_signature)
+                }
+                None => {
+                    return Err(CommandError::failed(format!("no help for unknown subcommand '{} {}'", name, sub)))
+                }
+            },
+        };
+
+        let mut output = Output::new();
+        if description.is_empty() {
+            output.push(title.clone());
+        } else {
+            output.push(format!("{} - {}", title, description));
+        }
+        match usage.as_slice() {
+            [line] => output.push(format!("Usage: {}", line)),
+            lines => {
+                output.push("Usage:");
+                for line in lines {
+                    output.push(format!("  {}", line));
+                }
+            }
+        }
+
+        let subcommands: Vec<_> = signature.subcommands().collect();
+        if !subcommands.is_empty() {
+            output.push("Subcommands:");
+            let width = subcommands.iter().map(|(sub, _)| sub.len()).max().unwrap_or(0);
+            for (sub, sub_signature) in subcommands {
+                output.push(format!("  {:width$}  {}", sub, sub_signature.description(), width = width));
+            }
+        }
+
+        // Pentru o subcomanda, doar exemplele care o folosesc
+        let examples: Vec<_> = cmd
+            .examples()
+            .iter()
+            .filter(|example| *example == &title || example.starts_with(&format!("{} ", title)))
+            .collect();
+        if !examples.is_empty() {
+            output.push("Examples:");
+            for example in examples {
+                output.push(format!("  {}", example));
+            }
+        }
+        Ok(output)
+    }
+
+    fn help_overview(&self) -> Output {
+        const BUILTIN_HELP: [(&str, &str); 10] = [
+            ("help", "Show this list, or details with 'help <command> [subcommand]'"),
+            ("stop", "Stop reading commands"),
+            ("alias", "Define 'alias <name>=<command>', or list aliases"),
+            ("define", "Define 'define <name> = <line>' using $1..$9 and $@, or list macros"),
+            ("unalias", "Remove an alias or macro"),
+            ("set", "Set a variable with 'set <name> <words...>', used as $name, or list variables"),
+            ("unset", "Remove a variable"),
+            ("source", "Run the commands in a file"),
+            ("if", "'if [command]' ... 'else' ... 'end': branch on the (previous) command's status"),
+            ("for", "'for <name> in <words...>' ... 'end': repeat a block for each word"),
+        ];
+        let commands: Vec<(&str, &str)> =
+            self.commands.iter().map(|cmd| (cmd.get_name(), cmd.description())).chain(BUILTIN_HELP).collect();
+        let aliases: Vec<(&str, &str)> = self.definitions.aliases().collect();
+        let macros: Vec<(&str, &str)> = self.definitions.macros().collect();
+        let width = commands.iter().chain(&aliases).chain(&macros).map(|(name, _)| name.len()).max().unwrap_or(0);
+
+        let mut output = Output::new();
+        for (title, entries) in [("Available commands:", commands), ("Aliases:", aliases), ("Macros:", macros)] {
+            if entries.is_empty() {
+                continue;
+            }
+            output.push(title);
+            for (name, description) in entries {
+                output.push(format!("  {:width$}  {}", name, description, width = width));
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{HelloCommand, PingCommand, TimesCommand};
+
+    fn terminal() -> Terminal {
+        let mut terminal = Terminal::new();
+        terminal.register(Box::new(PingCommand));
+        terminal.register(Box::new(TimesCommand::default()));
+        terminal.register(Box::new(HelloCommand));
+        terminal
+    }
+
+    #[test]
+    fn runs_script_until_stop() {
+        let script = "ping\ntimes\n\n  times  \nHELLO world\nstop\nping\n";
+        let mut out = Vec::new();
+        terminal().run(script.as_bytes(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "pong!\ncommand called 1 times\ncommand called 2 times\n\
+             Unknown command: 'HELLO'. Did you mean 'hello'?\n"
+        );
+    }
+
+    #[test]
+    fn quoted_arguments_comments_and_continuation() {
+        let script = "# greeting\nhello 'dear team' # trailing\nhello \\\n  \"a b\" c\nhello \"oops\nping\n";
+        let mut out = Vec::new();
+        terminal().run(script.as_bytes(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Hello, dear team!\nHello, a b c!\n\
+             Error: unterminated double quote starting at column 7\npong!\n"
+        );
+    }
+
+    fn output_of(terminal: &mut Terminal, script: &str) -> String {
+        let mut out = Vec::new();
+        terminal.run(script.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn chaining_follows_exit_status() {
+        let mut terminal = terminal();
+        assert_eq!(
+            output_of(&mut terminal, "ping && hello team ; times\nnope && ping ; times || ping\nnope || ping\n"),
+            "pong!\nHello, team!\ncommand called 1 times\n\
+             Unknown command: 'nope'.\ncommand called 2 times\n\
+             Unknown command: 'nope'.\npong!\n"
+        );
+        assert_eq!(output_of(&mut terminal, "ping ; stop ; ping\nping\n"), "pong!\n");
+        assert_eq!(output_of(&mut terminal, "ping && | x\n"), "Error: syntax error: missing command around '|'\n");
+        assert_eq!(terminal.last_status(), 2);
+    }
+
+    #[test]
+    fn pipes_pass_output_as_input() {
+        let mut terminal = terminal();
+        terminal.register(Box::new(crate::CountCommand));
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        terminal.register(Box::new(crate::BookmarkCommand::with_connection(conn).unwrap()));
+
+        let script = "bk add rust https://rust-lang.org; bk add rustup https://rustup.rs\n\
+                      bk search rust | count\nhello | count x\nnope | count\n";
+        assert_eq!(
+            output_of(&mut terminal, script),
+            "Bookmark added successfully.\nBookmark added successfully.\n\
+             counted 3 lines\ncounted 1 args\n\
+             Unknown command: 'nope'.\ncounted 0 args\n"
+        );
+        assert_eq!(terminal.last_status(), 0);
+
+        output_of(&mut terminal, "ping | nope");
+        assert_eq!(terminal.last_status(), 127);
+    }
+
+    #[test]
+    fn completion_table_includes_subcommands_and_builtins() {
+        let mut terminal = terminal();
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        terminal.register(Box::new(crate::BookmarkCommand::with_connection(conn).unwrap()));
+        let completions = terminal.completions();
+        assert_eq!(completions.complete("bk a", 4), (3, vec!["add".to_string()]));
+        assert_eq!(completions.complete("st", 2), (0, vec!["stop".to_string()]));
+    }
+
+    #[test]
+    fn interactive_mode_prints_prompt() {
+        let mut out = Vec::new();
+        terminal().run_interactive("hello team\n".as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "> Hello, team!\n> ");
+    }
+
+    #[test]
+    fn dispatch_and_names() {
+        let mut terminal = terminal();
+        assert_eq!(terminal.command_names().collect::<Vec<_>>(), ["ping", "times", "hello"]);
+        let err = terminal.dispatch("nope", &[]).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown command: 'nope'.");
+        assert_eq!(terminal.dispatch("ping", &[]).unwrap(), Output::line("pong!"));
+    }
+
+    #[test]
+    fn suggests_close_command_names() {
+        let mut terminal = terminal();
+        terminal.register(Box::new(crate::CountCommand));
+        assert_eq!(
+            output_of(&mut terminal, "pnig\nhelo\ntime\nstpo\nxyz\nhelp tims\n"),
+            "Unknown command: 'pnig'. Did you mean 'ping'?\n\
+             Unknown command: 'helo'. Did you mean 'hello' or 'help'?\n\
+             Unknown command: 'time'. Did you mean 'times'?\n\
+             Unknown command: 'stpo'. Did you mean 'stop'?\n\
+             Unknown command: 'xyz'.\n\
+             Error: no help for unknown command 'tims'; did you mean 'times'?\n"
+        );
+    }
+
+    #[test]
+    fn autocorrects_single_unambiguous_match() {
+        let mut terminal = terminal();
+        terminal.set_autocorrect(true);
+        assert_eq!(
+            output_of(&mut terminal, "pnig\nhelo you\nhello | cuont\n"),
+            "(running 'ping' instead of 'pnig')\npong!\n\
+             Unknown command: 'helo'. Did you mean 'hello' or 'help'?\n\
+             Unknown command: 'cuont'.\n"
+        );
+        assert_eq!(terminal.last_status(), 127);
+    }
+
+    #[test]
+    fn tracks_exit_status() {
+        let mut terminal = terminal();
+        let mut out = Vec::new();
+        for (line, status) in [("ping", 0), ("pnig", 127), ("hello 'x", 2), ("help nope", 1), ("times", 0)] {
+            terminal.process_line(line, &mut out).unwrap();
+            assert_eq!(terminal.last_status(), status, "{}", line);
+        }
+    }
+
+    #[test]
+    fn stop_on_error_ends_the_script() {
+        let script = "ping\nnope\nping\n";
+        let mut terminal = terminal();
+        let mut out = Vec::new();
+        terminal.run(script.as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "pong!\nUnknown command: 'nope'.\npong!\n");
+        assert_eq!(terminal.last_status(), 0);
+
+        terminal.set_stop_on_error(true);
+        let mut out = Vec::new();
+        terminal.run(script.as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "pong!\nUnknown command: 'nope'.\n");
+        assert_eq!(terminal.last_status(), 127);
+    }
+
+    fn help_output(topic: &str) -> String {
+        let mut terminal = terminal();
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        terminal.register(Box::new(crate::BookmarkCommand::with_connection(conn).unwrap()));
+        let mut out = Vec::new();
+        terminal.process_line(&format!("help {}", topic), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn help_lists_commands() {
+        assert_eq!(
+            help_output(""),
+            "Available commands:\n\
+             \x20 ping     Reply with 'pong!'\n\
+             \x20 times    Print how many times this command was called\n\
+             \x20 hello    Greet the world, or whoever is named\n\
+             \x20 bk       Save and search bookmarks stored in bookmarks.db\n\
+             \x20 help     Show this list, or details with 'help <command> [subcommand]'\n\
+             \x20 stop     Stop reading commands\n\
+             \x20 alias    Define 'alias <name>=<command>', or list aliases\n\
+             \x20 define   Define 'define <name> = <line>' using $1..$9 and $@, or list macros\n\
+             \x20 unalias  Remove an alias or macro\n\
+             \x20 set      Set a variable with 'set <name> <words...>', used as $name, or list variables\n\
+             \x20 unset    Remove a variable\n\
+             \x20 source   Run the commands in a file\n\
+             \x20 if       'if [command]' ... 'else' ... 'end': branch on the (previous) command's status\n\
+             \x20 for      'for <name> in <words...>' ... 'end': repeat a block for each word\n"
+        );
+    }
+
+    #[test]
+    fn variables_expand_to_words() {
+        let mut terminal = with_bookmarks();
+        let script = "set who 'dear team'\nset langs rust go\nhello $who\nhello \"<$langs>\" '$who'\n\
+                      hello $missing | count\nnope; hello $?\nset\nunset who\nhello ${who}x\n";
+        assert_eq!(
+            output_of(&mut terminal, script),
+            "Hello, dear team!\nHello, <rust go> $who!\ncounted 1 lines\n\
+             Unknown command: 'nope'.\nHello, 127!\nlangs=rust go\nwho=dear team\nHello, x!\n"
+        );
+        assert_eq!(terminal.variable("langs"), Some(&["rust".to_string(), "go".to_string()][..]));
+        assert_eq!(
+            output_of(&mut terminal, "set x a; hello $x && set x b; hello $x\nnope || hello $?; hello $?\n"),
+            "Hello, a!\nHello, b!\nUnknown command: 'nope'.\nHello, 127!\nHello, 0!\n"
+        );
+        assert_eq!(
+            output_of(&mut terminal, "set 1x a\nunset nope\n"),
+            "Error: invalid variable name '1x'\nError: no variable named 'nope'\n"
+        );
+    }
+
+    #[test]
+    fn if_branches_on_status() {
+        let mut terminal = terminal();
+        let script = "nope\nif\n  hello yes\nelse\n  hello no\nend\n\
+                      if ping\n  hello pinged\nend\n\
+                      if nope\nend\n";
+        assert_eq!(
+            output_of(&mut terminal, script),
+            "Unknown command: 'nope'.\nHello, no!\npong!\nHello, pinged!\nUnknown command: 'nope'.\n"
+        );
+        assert_eq!(terminal.last_status(), 0);
+    }
+
+    #[test]
+    fn for_loops_over_words() {
+        let mut terminal = terminal();
+        let script = "set langs rust 'go lang'\nfor lang in c $langs\n  for n in 1 2\n    hello $lang $n\n  end\nend\n\
+                      for x in\n  ping\nend\nfor x in a; b\nend\n";
+        assert_eq!(
+            output_of(&mut terminal, script),
+            "Hello, c 1!\nHello, c 2!\nHello, rust 1!\nHello, rust 2!\nHello, go lang 1!\nHello, go lang 2!\n\
+             Error: unexpected ';' in 'for' words\n"
+        );
+    }
+
+    #[test]
+    fn blocks_stop_and_report_errors() {
+        let mut terminal = terminal();
+        assert_eq!(output_of(&mut terminal, "for x in a b\n  hello $x\n  stop\nend\nping\n"), "Hello, a!\n");
+        assert_eq!(
+            output_of(&mut terminal, "end\nping; if\nelse x\nfor x in a\nping\n"),
+            "Error: 'end' without 'if' or 'for'\npong!\nError: 'if' is only allowed at the start of a line\n\
+             Error: 'else' must be alone on its line\nError: missing 'end' for 'for'\n"
+        );
+        assert!(!terminal.in_block());
+
+        terminal.set_stop_on_error(true);
+        assert_eq!(output_of(&mut terminal, "for x in a b\n  nope\nend\nping\n"), "Unknown command: 'nope'.\n");
+    }
+
+    #[test]
+    fn interactive_prompt_inside_blocks() {
+        let mut out = Vec::new();
+        terminal().run_interactive("if\nping\nend\n".as_bytes(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "> . . pong!\n> ");
+    }
+
+    #[test]
+    fn source_runs_files() {
+        let dir = std::env::temp_dir().join(format!("laborator-source-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (inner, outer) = (dir.join("inner.txt"), dir.join("outer.txt"));
+        fs::write(&inner, "hello $who\nstop\nping\n").unwrap();
+        let script = format!(
+            "set who inner\nsource '{0}' | count\nsource '{0}'\nsource '{1}'\n",
+            inner.display(),
+            outer.display()
+        );
+        fs::write(&outer, script).unwrap();
+
+        let mut terminal = terminal();
+        terminal.register(Box::new(crate::CountCommand));
+        let mut out = Vec::new();
+        terminal.run_file(&outer, &mut out).unwrap();
+        let canonical = fs::canonicalize(&outer).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("counted 1 lines\nHello, inner!\nError: recursive source: {0} -> {0}\n", canonical.display())
+        );
+        assert_eq!(terminal.last_status(), 1);
+
+        assert_eq!(
+            output_of(&mut terminal, "source\nsource /nonexistent/x.txt\n"),
+            "Error: usage: source <file>\n\
+             Error: could not open '/nonexistent/x.txt': No such file or directory (os error 2)\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn with_bookmarks() -> Terminal {
+        let mut terminal = terminal();
+        terminal.register(Box::new(crate::CountCommand));
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        terminal.register(Box::new(crate::BookmarkCommand::with_connection(conn).unwrap()));
+        output_of(&mut terminal, "bk add rust https://rust-lang.org; bk add rustup https://rustup.rs\n");
+        terminal
+    }
+
+    #[test]
+    fn aliases_prepend_their_words() {
+        let mut terminal = with_bookmarks();
+        let script = "alias b=bk search\nalias n = b rust -n\nb rustup\nn 1 | count\nalias\n";
+        assert_eq!(
+            output_of(&mut terminal, script),
+            "Search results for 'rustup':\n  rustup -> https://rustup.rs\n\
+             counted 2 lines\nalias b=bk search\nalias n=b rust -n\n"
+        );
+        assert_eq!(
+            output_of(&mut terminal, "unalias b\nn 1\nunalias b\n"),
+            "Unknown command: 'b'. Did you mean 'bk' or 'n'?\nError: no alias or macro named 'b'\n"
+        );
+    }
+
+    #[test]
+    fn macros_substitute_arguments() {
+        let mut terminal = with_bookmarks();
+        let script = "define greet = hello $1; ping\n\
+                      define all = hello \"$@!\" && times\n\
+                      greet 'dear team' ignored\nall a 'b c'\ngreet x | count\ndefine\n";
+        assert_eq!(
+            output_of(&mut terminal, script),
+            "Hello, dear team!\npong!\nHello, a b c!!\ncommand called 1 times\ncounted 2 lines\n\
+             define all = hello \"$@!\" && times\ndefine greet = hello $1; ping\n"
+        );
+        assert_eq!(output_of(&mut terminal, "define quit = ping; stop; ping\nquit\nping\n"), "pong!\n");
+    }
+
+    #[test]
+    fn detects_recursive_definitions() {
+        let mut terminal = terminal();
+        let script = "alias a=b x\nalias b=a\na\n\
+                      define loop = ping; loop\nloop\n\
+                      alias m=mm\ndefine mm = m\nm\n";
+        assert_eq!(
+            output_of(&mut terminal, script),
+            "Error: recursive definition: a -> b -> a\n\
+             pong!\nError: recursive definition: loop -> loop\n\
+             Error: recursive definition: m -> mm -> m\n"
+        );
+        assert_eq!(terminal.last_status(), 1);
+
+        // O comanda reala cu acelasi nume nu este recursie
+        let script = "alias hello=hello dear\nhello team\ndefine ping = ping; ping\nping\n";
+        assert_eq!(output_of(&mut terminal, script), "Hello, dear team!\npong!\npong!\n");
+    }
+
+    #[test]
+    fn definitions_in_help_completion_and_suggestions() {
+        let mut terminal = terminal();
+        output_of(&mut terminal, "alias hi=hello you\ndefine greet = hello $1; ping\n");
+        assert_eq!(
+            output_of(&mut terminal, "help hi\nhelp greet\ngreat\nhelp gret\n"),
+            "hi - alias for 'hello you'\ngreet - macro for 'hello $1; ping'\n\
+             Unknown command: 'great'. Did you mean 'greet'?\n\
+             Error: no help for unknown command 'gret'; did you mean 'greet'?\n"
+        );
+        let overview = output_of(&mut terminal, "help\n");
+        let definitions = "Aliases:\n  hi       hello you\nMacros:\n  greet    hello $1; ping\n";
+        assert!(overview.ends_with(definitions), "{}", overview);
+        assert_eq!(terminal.completions().complete("gr", 2), (0, vec!["greet".to_string()]));
+    }
+
+    #[test]
+    fn rejects_invalid_definitions() {
+        let mut terminal = terminal();
+        assert_eq!(
+            output_of(&mut terminal, "alias help=ping\ndefine x = 'oops\nping; alias x=ping\n"),
+            "Error: 'help' is a built-in command and cannot be redefined\n\
+             Error: invalid body for 'x': unterminated single quote starting at column 1\n\
+             pong!\nError: 'alias' must start a line of its own: alias <name>=<text>\n"
+        );
+        assert_eq!(terminal.last_status(), 1);
+    }
+
+    #[test]
+    fn loads_definitions_from_config() {
+        let mut terminal = terminal();
+        let config = "# aliases\nalias p=ping\n\ndefine twice = $1; $1\n";
+        assert_eq!(terminal.load_definitions(config.as_bytes()).unwrap(), 2);
+        assert_eq!(output_of(&mut terminal, "twice p\n"), "pong!\npong!\n");
+
+        let err = terminal.load_definitions("alias q=ping\nping\n".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "line 2: expected an 'alias' or 'define' line");
+    }
+
+    #[test]
+    fn help_for_command_and_subcommand() {
+        assert_eq!(
+            help_output("bk"),
+            "bk - Save and search bookmarks stored in bookmarks.db\n\
+             Usage:\n\
+             \x20 bk add <name> <url>\n\
+             \x20 bk search <name> [-n|--limit <limit>]\n\
+             Subcommands:\n\
+             \x20 add     Save a bookmark under a name\n\
+             \x20 search  List bookmarks whose name contains the given text\n\
+             Examples:\n\
+             \x20 bk add rust https://rust-lang.org\n\
+             \x20 bk search rust\n\
+             \x20 bk search rust --limit 5\n"
+        );
+        assert_eq!(
+            help_output("bk add"),
+            "bk add - Save a bookmark under a name\n\
+             Usage: bk add <name> <url>\n\
+             Examples:\n\
+             \x20 bk add rust https://rust-lang.org\n"
+        );
+        assert_eq!(
+            help_output("hello"),
+            "hello - Greet the world, or whoever is named\nUsage: hello [name...]\n\
+             Examples:\n  hello\n  hello dear team\n"
+        );
+        assert_eq!(help_output("bk del"), "Error: no help for unknown subcommand 'bk del'\n");
+        assert_eq!(help_output("nope"), "Error: no help for unknown command 'nope'; type 'help' for a list\n");
+    }
+
+    #[test]
+    fn invalid_arguments_print_generated_usage() {
+        let mut terminal = Terminal::new();
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        terminal.register(Box::new(crate::BookmarkCommand::with_connection(conn).unwrap()));
+        let mut out = Vec::new();
+        terminal.run("bk add rust\nbk\nbk search rust -n x\n".as_bytes(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Error: missing argument <url>\nUsage: bk add <name> <url>\n\
+             Error: missing subcommand, use 'add' or 'search'\n\
+             Usage: bk add <name> <url> OR bk search <name> [-n|--limit <limit>]\n\
+             Error: invalid value 'x' for <limit>: expected an integer\n\
+             Usage: bk search <name> [-n|--limit <limit>]\n"
+        );
+    }
+}
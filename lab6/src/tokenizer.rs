@@ -0,0 +1,293 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+// --- Impartirea unei linii in cuvinte, ca intr-un shell ---
+//
+// - spatiile separa cuvintele; `'...'` si `"..."` pastreaza spatiile
+// - intre ghilimele simple totul este literal; intre ghilimele duble `\` scapa
+//   doar `"`, `\` si `$`
+// - in afara ghilimelelor `\` scapa orice caracter, iar `\` la final de linie
+//   continua comanda pe linia urmatoare
+// - `#` la inceputul unui cuvant incepe un comentariu pana la final de linie
+// - `;`, `&&`, `||` si `|` (in afara ghilimelelor) sunt operatori, chiar lipiti
+//   de cuvinte (`ping;times`); un singur `&` ramane caracter obisnuit
+
+/// Un cuvant sau un operator de inlantuire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Word(String),
+    Op(Operator),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operator {
+    /// `;` - ruleaza urmatoarea comanda oricum
+    Then,
+    /// `&&` - doar daca precedenta a reusit
+    And,
+    /// `||` - doar daca precedenta a esuat
+    Or,
+    /// `|` - iesirea precedentei devine intrarea urmatoarei
+    Pipe,
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Operator::Then => ";",
+            Operator::And => "&&",
+            Operator::Or => "||",
+            Operator::Pipe => "|",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenizeError {
+    /// Ghilimeaua deschisa la `line`/`column` (numerotate de la 1) nu a fost inchisa
+    UnterminatedQuote { quote: char, line: usize, column: usize },
+    /// Linia se termina cu `\`: comanda continua pe linia urmatoare
+    TrailingBackslash,
+}
+
+impl Display for TokenizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenizeError::UnterminatedQuote { quote, line: 1, column } => {
+                write!(
+                    f,
+                    "unterminated {} quote starting at column {}",
+                    quote_name(*quote),
+                    column
+                )
+            }
+            TokenizeError::UnterminatedQuote { quote, line, column } => write!(
+                f,
+                "unterminated {} quote starting at line {}, column {}",
+                quote_name(*quote),
+                line,
+                column
+            ),
+            TokenizeError::TrailingBackslash => write!(f, "unexpected end of input after '\\'"),
+        }
+    }
+}
+
+fn quote_name(quote: char) -> &'static str {
+    if quote == '\'' {
+        "single"
+    } else {
+        "double"
+    }
+}
+
+impl Error for TokenizeError {}
+
+/// Imparte `line` in cuvinte si operatori. Un `\` urmat de newline este o continuare de linie.
+pub fn tokenize(line: &str) -> Result<Vec<Token>, TokenizeError> {
+    Ok(tokenize_spans(line)?.into_iter().map(|(token, _)| token).collect())
+}
+
+/// Ca `tokenize`, dar fiecare token vine cu pozitia (in bytes) din `line` la care se termina
+pub(crate) fn tokenize_spans(line: &str) -> Result<Vec<(Token, usize)>, TokenizeError> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    // Un cuvant poate fi gol (`""`), deci tinem minte separat daca a inceput
+    let mut in_word = false;
+    let (mut line_no, mut column) = (1, 0);
+    let mut chars = line.chars();
+    let offset = |chars: &std::str::Chars| line.len() - chars.as_str().len();
+
+    loop {
+        let at = offset(&chars);
+        let Some(c) = chars.next() else { break };
+        column += 1;
+        match c {
+            '\\' => match chars.next() {
+                Some('\n') => {
+                    line_no += 1;
+                    column = 0;
+                }
+                Some(escaped) => {
+                    column += 1;
+                    current.push(escaped);
+                    in_word = true;
+                }
+                None => return Err(TokenizeError::TrailingBackslash),
+            },
+            '\'' | '"' => {
+                let (start_line, start_column) = (line_no, column);
+                in_word = true;
+                loop {
+                    let Some(inner) = chars.next() else {
+                        return Err(TokenizeError::UnterminatedQuote {
+                            quote: c,
+                            line: start_line,
+                            column: start_column,
+                        });
+                    };
+                    column += 1;
+                    match inner {
+                        _ if inner == c => break,
+                        '\\' if c == '"' => match chars.next() {
+                            Some(escaped @ ('"' | '\\' | '$')) => {
+                                column += 1;
+                                current.push(escaped);
+                            }
+                            Some('\n') => {
+                                line_no += 1;
+                                column = 0;
+                            }
+                            Some(other) => {
+                                column += 1;
+                                current.push('\\');
+                                current.push(other);
+                            }
+                            None => {
+                                return Err(TokenizeError::UnterminatedQuote {
+                                    quote: c,
+                                    line: start_line,
+                                    column: start_column,
+                                })
+                            }
+                        },
+                        '\n' => {
+                            line_no += 1;
+                            column = 0;
+                            current.push(inner);
+                        }
+                        _ => current.push(inner),
+                    }
+                }
+            }
+            '#' if !in_word => break,
+            ';' | '|' | '&' if c != '&' || chars.clone().next() == Some('&') => {
+                if in_word {
+                    tokens.push((Token::Word(std::mem::take(&mut current)), at));
+                    in_word = false;
+                }
+                let op = match c {
+                    ';' => Operator::Then,
+                    '&' => Operator::And,
+                    _ if chars.clone().next() == Some('|') => Operator::Or,
+                    _ => Operator::Pipe,
+                };
+                if matches!(op, Operator::And | Operator::Or) {
+                    chars.next();
+                    column += 1;
+                }
+                tokens.push((Token::Op(op), offset(&chars)));
+            }
+            _ if c.is_whitespace() => {
+                if c == '\n' {
+                    line_no += 1;
+                    column = 0;
+                }
+                if in_word {
+                    tokens.push((Token::Word(std::mem::take(&mut current)), at));
+                    in_word = false;
+                }
+            }
+            _ => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+
+    if in_word {
+        tokens.push((Token::Word(current), line.len()));
+    }
+    Ok(tokens)
+}
+
+/// `true` daca linia (fara newline) cere continuarea pe linia urmatoare
+pub fn needs_continuation(line: &str) -> bool {
+    tokenize(line.trim_end_matches(['\n', '\r'])) == Err(TokenizeError::TrailingBackslash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(line: &str) -> Vec<String> {
+        tokenize(line)
+            .unwrap()
+            .into_iter()
+            .map(|token| match token {
+                Token::Word(word) => word,
+                Token::Op(op) => format!("<{}>", op),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_on_whitespace() {
+        assert_eq!(words("  bk  add\trust https://x "), ["bk", "add", "rust", "https://x"]);
+        assert!(words("   ").is_empty());
+    }
+
+    #[test]
+    fn quotes_and_escapes() {
+        assert_eq!(
+            words(r#"bk add "My Site" https://x"#),
+            ["bk", "add", "My Site", "https://x"]
+        );
+        assert_eq!(
+            words(r#"say 'it''s' "a \"b\" \\ \n" a\ b"#),
+            ["say", "its", r#"a "b" \ \n"#, "a b"]
+        );
+        assert_eq!(words(r#"x '\"' "" '' "\$x""#), ["x", r#"\""#, "", "", "$x"]);
+        assert_eq!(words(r#"pre"fix"'ed'"#), ["prefixed"]);
+    }
+
+    #[test]
+    fn comments() {
+        assert!(words("# only a comment").is_empty());
+        assert_eq!(words("ping # pong"), ["ping"]);
+        assert_eq!(words("hello a#b '#c' \\#d"), ["hello", "a#b", "#c", "#d"]);
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            words("ping && hello team ; times"),
+            ["ping", "<&&>", "hello", "team", "<;>", "times"]
+        );
+        assert_eq!(
+            words("bk search rust|count||ping;"),
+            ["bk", "search", "rust", "<|>", "count", "<||>", "ping", "<;>"]
+        );
+        assert_eq!(words("a&b '|' \\; \"&&\""), ["a&b", "|", ";", "&&"]);
+    }
+
+    #[test]
+    fn line_continuation() {
+        assert_eq!(words("hello \\\n  team\n"), ["hello", "team"]);
+        assert_eq!(words("hel\\\nlo"), ["hello"]);
+        assert!(needs_continuation("hello \\\n"));
+        assert!(!needs_continuation("hello \\\\\n"));
+        assert!(!needs_continuation("hello '\\'\n"));
+    }
+
+    #[test]
+    fn unterminated_quotes_report_position() {
+        let err = tokenize(r#"bk add "My Site https://x"#).unwrap_err();
+        assert_eq!(
+            err,
+            TokenizeError::UnterminatedQuote {
+                quote: '"',
+                line: 1,
+                column: 8
+            }
+        );
+        assert_eq!(err.to_string(), "unterminated double quote starting at column 8");
+
+        let err = tokenize("hello \\\n  'team").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unterminated single quote starting at line 2, column 3"
+        );
+    }
+}
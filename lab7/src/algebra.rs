@@ -0,0 +1,327 @@
+use std::fmt::{Alignment, Display, Formatter, LowerExp, Result as FmtResult, UpperExp, Write};
+
+use crate::num::Num;
+
+// --- Infrastructura comuna pentru Complex, Quaternion si Dual ---
+//
+// Toate trei sunt algebre peste componente `T: Num`: adunarea, scaderea si
+// negarea se fac pe componente, iar inmultirea si impartirea sunt specifice
+// fiecarui tip (trait-ul `Algebra`). Macro-ul `impl_algebra!` genereaza, din
+// lista de campuri, aceiasi operatori (`Add<R: Into<Self>>`, `*Assign`, `Neg`,
+// variantele pe `&Self`), `Sum`/`Product`,
+// `From<T>`, `PartialEq` si formatarea (`Display`, `LowerExp`, `UpperExp`) pentru fiecare tip.
+
+/// Regulile de inmultire si impartire ale unei algebre.
+pub trait Algebra: Copy + From<Self::Scalar> {
+    type Scalar: Num;
+
+    /// Produsul `self * rhs` (pentru cuaternioni: produsul Hamilton, necomutativ)
+    fn product(self, rhs: Self) -> Self;
+
+    /// Catul `self / rhs`, adica `self * rhs^-1`
+    fn quotient(self, rhs: Self) -> Self;
+}
+
+/// Afiseaza componentele nenule, fiecare urmata de unitatea ei (`""`, `"i"`, `"j"`, ...).
+/// Toate nule -> `"0"`; o componenta negativa dupa prima -> `-` in loc de `+-`.
+///
+/// Respecta flag-urile formatter-ului: precizia se aplica fiecarei componente,
+/// `+` forteaza semnul primei componente, iar latimea/alinierea/umplerea se
+/// aplica intregului numar (implicit aliniat la dreapta, ca un float).
+/// `fmt_value` formateaza o singura componenta (Display, LowerExp sau UpperExp).
+pub(crate) fn fmt_parts<T: Num>(
+    f: &mut Formatter<'_>,
+    parts: &[(T, &str)],
+    fmt_value: impl Fn(T, Option<usize>) -> String,
+) -> FmtResult {
+    let precision = f.precision();
+    let mut out = String::new();
+    let mut first = true;
+    for &(value, unit) in parts {
+        // Comparatie exacta: o componenta mica (ex: 1e-4) nu trebuie ascunsa
+        if value == T::ZERO {
+            continue;
+        }
+        if first {
+            if f.sign_plus() && value >= T::ZERO {
+                out.push('+');
+            }
+            out += &fmt_value(value, precision);
+        } else if value < T::ZERO {
+            // "1-2i" in loc de "1+-2i"; semnul se scoate din text, nu cu abs(),
+            // care ar depasi pentru `T::MIN` la intregi
+            out.push('-');
+            let text = fmt_value(value, precision);
+            out += text.strip_prefix('-').unwrap_or(&text);
+        } else {
+            out.push('+');
+            out += &fmt_value(value, precision);
+        }
+        out += unit;
+        first = false;
+    }
+    if first {
+        if f.sign_plus() {
+            out.push('+');
+        }
+        out += &fmt_value(T::ZERO, precision);
+    }
+    pad(f, &out)
+}
+
+/// Aplica latimea, umplerea si alinierea din `f` (implicit la dreapta).
+/// Nu folosim `Formatter::pad`, care ar interpreta precizia ca trunchiere de sir.
+pub(crate) fn pad(f: &mut Formatter<'_>, text: &str) -> FmtResult {
+    let len = text.chars().count();
+    let padding = f.width().map_or(0, |w| w.saturating_sub(len));
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn display_value<T: Display>(x: T, precision: Option<usize>) -> String {
+    match precision {
+        Some(p) => format!("{:.*}", p, x),
+        None => format!("{}", x),
+    }
+}
+
+pub(crate) fn lower_exp_value<T: LowerExp>(x: T, precision: Option<usize>) -> String {
+    match precision {
+        Some(p) => format!("{:.*e}", p, x),
+        None => format!("{:e}", x),
+    }
+}
+
+pub(crate) fn upper_exp_value<T: UpperExp>(x: T, precision: Option<usize>) -> String {
+    match precision {
+        Some(p) => format!("{:.*E}", p, x),
+        None => format!("{:E}", x),
+    }
+}
+
+/// Genereaza operatorii (si pe referinte), `Sum`/`Product`, `From<T>`, `PartialEq`,
+/// `Display` si `LowerExp`/`UpperExp` pentru o algebra.
+///
+/// Primul camp este partea scalara (cea in care intra `From<T>`); fiecare camp
+/// are unitatea afisata de `Display`, ex: `impl_algebra!(Complex { real: "", imag: "i" })`.
+macro_rules! impl_algebra {
+    ($ty:ident { $first:ident : $first_unit:expr $(, $field:ident : $unit:expr)* }) => {
+        impl<T: $crate::num::Num> From<T> for $ty<T> {
+            fn from(n: T) -> Self {
+                $ty {
+                    $first: n,
+                    $($field: T::ZERO,)*
+                }
+            }
+        }
+
+        impl<T: $crate::num::Num> PartialEq for $ty<T> {
+            /// Compara pe componente cu `Tolerance::DEFAULT`, adica `|x - y| <= 0.001`.
+            ///
+            /// Marja absoluta ramane intentionat: este contractul din enuntul laboratorului
+            /// (`PartialEq` prin `eq_rel`), pe care se bazeaza `assert_eq!` din teste. Ea nu
+            /// se potriveste cu toate scarile: sub ~1e-3 valori diferite sunt "egale"
+            /// (`1e-5 == 2e-5`), iar peste ~1e13 `==` devine practic egalitate exacta.
+            /// Pentru alte scari se foloseste `approx_eq(.., Tolerance::Relative/Ulps)`,
+            /// iar pentru egalitate exacta `bitwise_eq`.
+            fn eq(&self, other: &Self) -> bool {
+                self.$first.approx_eq(other.$first) $(&& self.$field.approx_eq(other.$field))*
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Add<R> for $ty<T> {
+            type Output = $ty<T>;
+            fn add(self, rhs: R) -> Self::Output {
+                let rhs = rhs.into();
+                $ty {
+                    $first: self.$first + rhs.$first,
+                    $($field: self.$field + rhs.$field,)*
+                }
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Sub<R> for $ty<T> {
+            type Output = $ty<T>;
+            fn sub(self, rhs: R) -> Self::Output {
+                let rhs = rhs.into();
+                $ty {
+                    $first: self.$first - rhs.$first,
+                    $($field: self.$field - rhs.$field,)*
+                }
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Mul<R> for $ty<T> {
+            type Output = $ty<T>;
+            fn mul(self, rhs: R) -> Self::Output {
+                $crate::algebra::Algebra::product(self, rhs.into())
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Div<R> for $ty<T> {
+            type Output = $ty<T>;
+            fn div(self, rhs: R) -> Self::Output {
+                $crate::algebra::Algebra::quotient(self, rhs.into())
+            }
+        }
+
+        impl<T: $crate::num::Num> std::ops::Neg for $ty<T> {
+            type Output = $ty<T>;
+            fn neg(self) -> Self::Output {
+                $ty {
+                    $first: -self.$first,
+                    $($field: -self.$field,)*
+                }
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::AddAssign<R> for $ty<T> {
+            fn add_assign(&mut self, rhs: R) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::SubAssign<R> for $ty<T> {
+            fn sub_assign(&mut self, rhs: R) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::MulAssign<R> for $ty<T> {
+            fn mul_assign(&mut self, rhs: R) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::DivAssign<R> for $ty<T> {
+            fn div_assign(&mut self, rhs: R) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<T: $crate::num::Num> From<&$ty<T>> for $ty<T> {
+            fn from(value: &$ty<T>) -> Self {
+                *value
+            }
+        }
+
+        // Operanzi prin referinta: `&a + &b`, `&a * 2`, `a - &b` (ultimul prin `From<&Self>`)
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Add<R> for &$ty<T> {
+            type Output = $ty<T>;
+            fn add(self, rhs: R) -> Self::Output {
+                *self + rhs
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Sub<R> for &$ty<T> {
+            type Output = $ty<T>;
+            fn sub(self, rhs: R) -> Self::Output {
+                *self - rhs
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Mul<R> for &$ty<T> {
+            type Output = $ty<T>;
+            fn mul(self, rhs: R) -> Self::Output {
+                *self * rhs
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::ops::Div<R> for &$ty<T> {
+            type Output = $ty<T>;
+            fn div(self, rhs: R) -> Self::Output {
+                *self / rhs
+            }
+        }
+
+        impl<T: $crate::num::Num> std::ops::Neg for &$ty<T> {
+            type Output = $ty<T>;
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
+
+        // Suma unui iterator porneste de la 0, produsul de la 1 (in ordinea elementelor)
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::iter::Sum<R> for $ty<T> {
+            fn sum<I: Iterator<Item = R>>(iter: I) -> Self {
+                iter.fold($ty::from(T::ZERO), |acc, x| acc + x)
+            }
+        }
+
+        impl<T: $crate::num::Num, R: Into<$ty<T>>> std::iter::Product<R> for $ty<T> {
+            fn product<I: Iterator<Item = R>>(iter: I) -> Self {
+                iter.fold($ty::from(T::ONE), |acc, x| acc * x)
+            }
+        }
+
+        impl<T: $crate::num::Num> std::fmt::Display for $ty<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let parts = [(self.$first, $first_unit) $(, (self.$field, $unit))*];
+                $crate::algebra::fmt_parts(f, &parts, $crate::algebra::display_value)
+            }
+        }
+
+        impl<T: $crate::num::Num + std::fmt::LowerExp> std::fmt::LowerExp for $ty<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let parts = [(self.$first, $first_unit) $(, (self.$field, $unit))*];
+                $crate::algebra::fmt_parts(f, &parts, $crate::algebra::lower_exp_value)
+            }
+        }
+
+        impl<T: $crate::num::Num + std::fmt::UpperExp> std::fmt::UpperExp for $ty<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let parts = [(self.$first, $first_unit) $(, (self.$field, $unit))*];
+                $crate::algebra::fmt_parts(f, &parts, $crate::algebra::upper_exp_value)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_algebra;
+
+#[cfg(test)]
+mod tests {
+    use crate::complex::Complex;
+    use crate::quaternion::Quaternion;
+
+    #[test]
+    fn precision_applies_to_every_component() {
+        let z = Complex::new(1.0 / 3.0, -2.0 / 3.0);
+        assert_eq!(format!("{:.3}", z), "0.333-0.667i");
+        assert_eq!(format!("{:.0}", Complex::new(7, 0)), "7");
+        assert_eq!(format!("{:.2}", Complex::new(0, 0)), "0.00");
+        assert_eq!(format!("{:.1}", Quaternion::from_parts(1.0, 0.25, 0.0, -2.0)), "1.0+0.2i-2.0k");
+    }
+
+    #[test]
+    fn width_alignment_and_sign() {
+        let z = Complex::new(1, -2);
+        assert_eq!(format!("{:8}", z), "    1-2i");
+        assert_eq!(format!("{:<8}|", z), "1-2i    |");
+        assert_eq!(format!("{:*^9}", z), "**1-2i***");
+        assert_eq!(format!("{:+}", z), "+1-2i");
+        assert_eq!(format!("{:+}", Complex::new(0, -5)), "-5i");
+        assert_eq!(format!("{:+.1}", Complex::new(0, 0)), "+0.0");
+        assert_eq!(format!("{:>+10.2}", Complex::new(0.5, 0.25)), "+0.50+0.25i");
+    }
+
+    #[test]
+    fn exponent_formats() {
+        let z = Complex::new(1234.5, -0.00012);
+        assert_eq!(format!("{:e}", z), "1.2345e3-1.2e-4i");
+        assert_eq!(format!("{:.2E}", z), "1.23E3-1.20E-4i");
+        assert_eq!(format!("{:e}", Complex::from_parts(1500, 0)), "1.5e3");
+    }
+}
@@ -0,0 +1,362 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::algebra::{impl_algebra, Algebra};
+use crate::num::{Num, Tolerance};
+
+// --- Definirea Structurii Complex ---
+
+/// Numar complex generic peste tipul componentelor (implicit `f64`).
+///
+/// Cu feature-ul `serde`, forma implicita este `{ "real": .., "imag": .. }`;
+/// vezi modulul `serialization` pentru formele compacte.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Complex<T = f64> {
+    pub real: T,
+    pub imag: T,
+}
+
+// --- Implementarea functiilor asociate ---
+
+impl Complex {
+    /// Creeaza un numar complex nou din tipuri generice care pot fi convertite in f64
+    pub fn new<A, B>(real: A, imag: B) -> Self
+    where
+        A: Into<f64>,
+        B: Into<f64>,
+    {
+        Complex {
+            real: real.into(),
+            imag: imag.into(),
+        }
+    }
+}
+
+impl<T: Num> Complex<T> {
+    /// Creeaza un numar complex direct din componente, pentru orice `T: Num`
+    /// (ex: `Complex::<f32>::from_parts(1.0, 2.0)` sau `Complex::from_parts(3, 4)`)
+    pub fn from_parts(real: T, imag: T) -> Self {
+        Complex { real, imag }
+    }
+
+    /// Returneaza conjugatul numarului complex
+    pub fn conjugate(&self) -> Self {
+        Complex {
+            real: self.real,
+            imag: -self.imag,
+        }
+    }
+
+    /// Patratul modulului: `real^2 + imag^2`
+    pub fn norm_sqr(&self) -> T {
+        self.real * self.real + self.imag * self.imag
+    }
+
+    /// Compara pe componente, cu toleranta explicita
+    pub fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+        self.real.approx_eq_tol(other.real, tol) && self.imag.approx_eq_tol(other.imag, tol)
+    }
+
+    /// Egalitate stricta, bit cu bit, a ambelor componente
+    pub fn bitwise_eq(&self, other: &Self) -> bool {
+        self.real.bitwise_eq(other.real) && self.imag.bitwise_eq(other.imag)
+    }
+
+    /// `true` daca ambele componente sunt exact zero
+    pub fn is_zero(&self) -> bool {
+        self.real == T::ZERO && self.imag == T::ZERO
+    }
+
+    /// Inversul numarului complex: `conj(z) / |z|^2`
+    ///
+    /// Pentru float-uri, `recip` al lui zero produce NaN/inf; vezi `checked_recip`.
+    pub fn recip(&self) -> Self {
+        let (real, imag) = T::complex_div((T::ONE, T::ZERO), (self.real, self.imag));
+        Complex { real, imag }
+    }
+
+    /// Ca `recip`, dar intoarce `DivisionByZero` in loc de NaN/inf (sau panica, la intregi)
+    pub fn checked_recip(&self) -> Result<Self, DivisionByZero> {
+        if self.is_zero() {
+            return Err(DivisionByZero);
+        }
+        Ok(self.recip())
+    }
+
+    /// Ca operatorul `/`, dar intoarce `DivisionByZero` daca impartitorul este zero
+    pub fn checked_div<R: Into<Complex<T>>>(self, rhs: R) -> Result<Self, DivisionByZero> {
+        let rhs = rhs.into();
+        if rhs.is_zero() {
+            return Err(DivisionByZero);
+        }
+        Ok(self / rhs)
+    }
+}
+
+// --- Eroarea pentru impartirea la zero ---
+
+/// Eroarea intoarsa de `checked_div` / `checked_recip` cand impartitorul este `0+0i`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DivisionByZero;
+
+impl Display for DivisionByZero {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "complex division by zero")
+    }
+}
+
+impl Error for DivisionByZero {}
+
+// --- Implementarea Trait-ului `From` ---
+
+// Conversii fara pierdere de precizie intre instantieri diferite
+macro_rules! impl_from_widening {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl From<$from> for Complex<$to> {
+                fn from(n: $from) -> Self {
+                    Complex {
+                        real: n.into(),
+                        imag: <$to as Num>::ZERO,
+                    }
+                }
+            }
+
+            impl From<Complex<$from>> for Complex<$to> {
+                fn from(c: Complex<$from>) -> Self {
+                    Complex {
+                        real: c.real.into(),
+                        imag: c.imag.into(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_widening!(i32 => f64, f32 => f64, i32 => i64);
+
+/// `i64` nu are `Into<f64>` (pierde precizie peste 2^53); conversia explicita
+/// permite totusi `z + 5i64` si `5i64 * z`
+impl From<i64> for Complex<f64> {
+    fn from(n: i64) -> Self {
+        Complex {
+            real: n as f64,
+            imag: 0.0,
+        }
+    }
+}
+
+/// Perechea `(real, imag)`
+impl<T: Num> From<(T, T)> for Complex<T> {
+    fn from((real, imag): (T, T)) -> Self {
+        Complex { real, imag }
+    }
+}
+
+// --- Operatori cu scalarul in stanga (`2.0 * z`, `5 + z`) ---
+
+// Regula orfanilor nu permite `impl<T> Add<Complex<T>> for T`, deci enumeram
+// perechile (scalar => componente) pentru care exista deja `From`.
+macro_rules! impl_scalar_lhs {
+    ($($scalar:ty => $to:ty),*) => {
+        $(
+            impl_scalar_lhs!(@op $scalar => $to, Add, add, +);
+            impl_scalar_lhs!(@op $scalar => $to, Sub, sub, -);
+            impl_scalar_lhs!(@op $scalar => $to, Mul, mul, *);
+            impl_scalar_lhs!(@op $scalar => $to, Div, div, /);
+        )*
+    };
+    (@op $scalar:ty => $to:ty, $trait:ident, $method:ident, $op:tt) => {
+        impl std::ops::$trait<Complex<$to>> for $scalar {
+            type Output = Complex<$to>;
+            fn $method(self, rhs: Complex<$to>) -> Complex<$to> {
+                Complex::<$to>::from(self) $op rhs
+            }
+        }
+
+        impl std::ops::$trait<&Complex<$to>> for $scalar {
+            type Output = Complex<$to>;
+            fn $method(self, rhs: &Complex<$to>) -> Complex<$to> {
+                Complex::<$to>::from(self) $op *rhs
+            }
+        }
+    };
+}
+
+impl_scalar_lhs!(
+    f64 => f64, f32 => f64, i32 => f64, i64 => f64,
+    f32 => f32,
+    i32 => i32,
+    i64 => i64, i32 => i64
+);
+
+// --- Implementarea Operatorilor (Generic) ---
+
+impl<T: Num> Algebra for Complex<T> {
+    type Scalar = T;
+
+    fn product(self, rhs: Self) -> Self {
+        // (a+bi)(c+di) = (ac-bd) + (ad+bc)i
+        let real_part = self.real * rhs.real - self.imag * rhs.imag;
+        let imag_part = self.real * rhs.imag + self.imag * rhs.real;
+        Complex {
+            real: real_part,
+            imag: imag_part,
+        }
+    }
+
+    fn quotient(self, rhs: Self) -> Self {
+        // (a+bi)/(c+di) = ((ac+bd) + (bc-ad)i) / (c^2+d^2), fara depasire la float-uri
+        let (real, imag) = T::complex_div((self.real, self.imag), (rhs.real, rhs.imag));
+        Complex { real, imag }
+    }
+}
+
+// Add/Sub/Mul/Div<R: Into<Complex<T>>> (si pe `&Complex<T>`), Neg, operatorii assign,
+// Sum/Product, From<T>, PartialEq si Display
+// (ex: "1+2i", "1-2i", "5i", "7", "0")
+impl_algebra!(Complex { real: "", imag: "i" });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_eq_rel;
+
+    #[test]
+    fn arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        assert_eq_rel!(a.real, 1);
+        assert_eq_rel!(a.imag, 2);
+
+        let b = Complex::new(2.0, 3);
+        let c = a + b;
+        assert_eq_rel!(c.real, 3);
+        assert_eq_rel!(c.imag, 5);
+
+        let d = c - a;
+        assert_eq!(b, d);
+
+        let e = (a * d).conjugate();
+        assert_eq_rel!(e.imag, -7);
+
+        let f = (a + b - d) * c;
+        assert_eq!(f, Complex::new(-7, 11));
+
+        let h = Complex::new(-4, -5);
+        let i = h - (h + 5) * 2.0;
+        assert_eq_rel!(i.real, -6);
+
+        let j = -i + i;
+        assert_eq_rel!(j.real, 0);
+        assert_eq_rel!(j.imag, 0);
+    }
+
+    #[test]
+    fn display_skips_zero_parts() {
+        assert_eq!(Complex::new(1, 2).to_string(), "1+2i");
+        assert_eq!(Complex::new(1, -2).to_string(), "1-2i");
+        assert_eq!(Complex::new(0, 5).to_string(), "5i");
+        assert_eq!(Complex::new(7, 0).to_string(), "7");
+        assert_eq!(Complex::new(0, 0).to_string(), "0");
+        assert_eq!(Complex::new(1e-4, 0).to_string(), "0.0001");
+        assert_eq!(Complex::from_parts(i32::MIN, i32::MIN).to_string(), "-2147483648-2147483648i");
+    }
+
+    #[test]
+    fn other_component_types() {
+        let k = Complex::<f32>::from_parts(1.5, -2.0);
+        assert_eq!(k * 2.0, Complex::from_parts(3.0, -4.0));
+        assert_eq!((k + k.conjugate()).to_string(), "3");
+        let k64: Complex = k.into();
+        assert_eq!(k64, Complex::new(1.5, -2.0));
+
+        let mut g = Complex::from_parts(3, 4);
+        g *= g.conjugate();
+        assert_eq!(g, Complex::from(25));
+        g -= Complex::from_parts(0, 7);
+        assert_eq!(g.to_string(), "25-7i");
+        let g64: Complex<i64> = Complex::from_parts(i32::MAX, 1).into();
+        assert_eq!((g64 + 1).real, i32::MAX as i64 + 1);
+    }
+
+    #[test]
+    fn division() {
+        let q = Complex::new(-7, 11) / Complex::new(1, 2);
+        assert_eq!(q, Complex::new(3, 5));
+        assert_eq!(Complex::new(0, 1).recip(), Complex::new(0, -1));
+        let mut r = Complex::new(4, -2);
+        r /= 2;
+        assert_eq!(r, Complex::new(2, -1));
+        assert_eq!(q.checked_div(0), Err(DivisionByZero));
+        assert_eq!(Complex::<f64>::from(0).checked_recip(), Err(DivisionByZero));
+    }
+
+    #[test]
+    fn explicit_tolerance() {
+        let tiny = Complex::new(1e-4, 0);
+        assert_eq!(tiny, Complex::new(0, 0));
+        assert!(!tiny.approx_eq(&Complex::new(0, 0), Tolerance::Absolute(1e-6)));
+        let big = Complex::new(1e12, -3e12);
+        assert!(big.approx_eq(&(big * 1.000_000_1), Tolerance::Relative(1e-6)));
+        assert!(!big.approx_eq(&(big + 1.0), Tolerance::Ulps(4)));
+        assert!(Complex::new(0.1 + 0.2, 0).approx_eq(&Complex::new(0.3, 0), Tolerance::Ulps(1)));
+        assert!(!Complex::new(0.1 + 0.2, 0).bitwise_eq(&Complex::new(0.3, 0)));
+        // `==` ramane cu marja absoluta 0.001, deci nu distinge valori mici
+        assert_eq!(Complex::new(1e-5, 0), Complex::new(2e-5, 0));
+        assert!(!Complex::new(1e-5, 0).approx_eq(&Complex::new(2e-5, 0), Tolerance::Relative(1e-6)));
+    }
+
+    #[test]
+    fn scalar_on_the_left() {
+        let z = Complex::new(1, -2);
+        assert_eq!(2.0 * z, Complex::new(2, -4));
+        assert_eq!(5 + z, Complex::new(6, -2));
+        assert_eq!(1 - z, Complex::new(0, 2));
+        assert_eq!(5i64 / Complex::new(0, 1), Complex::new(0, -5));
+        assert_eq!(2.5f32 * Complex::<f32>::from_parts(2.0, 1.0), Complex::from_parts(5.0f32, 2.5));
+        assert_eq!(3 * Complex::<i64>::from_parts(1, 2), Complex::from_parts(3i64, 6));
+        assert_eq!(2 * &z, z * 2);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference_operands() {
+        let a = Complex::new(1, 2);
+        let b = Complex::new(3, -1);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - b, a - b);
+        assert_eq!(a * &b, a * b);
+        assert_eq!(&a / 2, Complex::new(0.5, 1));
+        assert_eq!(-&a, -a);
+    }
+
+    #[test]
+    fn division_of_large_values() {
+        let big = Complex::new(1e200, 1e200);
+        assert!((big / big).bitwise_eq(&Complex::new(1, 0)));
+        assert!(big.recip().approx_eq(&Complex::new(5e-201, -5e-201), Tolerance::Relative(1e-12)));
+        let q = Complex::new(3e300, -1e300) / Complex::new(0, 1e300);
+        assert!(q.approx_eq(&Complex::new(-1, -3), Tolerance::Ulps(4)));
+        assert_eq!(Complex::from_parts(7, 3) / Complex::from_parts(1, 1), Complex::from_parts(5, -2));
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let zs = [Complex::new(1, 1), Complex::new(2, -3), Complex::new(0, 1)];
+        assert_eq!(zs.iter().sum::<Complex>(), Complex::new(3, -1));
+        assert_eq!(zs.into_iter().product::<Complex>(), Complex::new(1, 5));
+        assert_eq!([1.0, 2.0].into_iter().sum::<Complex>(), Complex::from(3.0));
+        assert_eq!(std::iter::empty::<Complex>().sum::<Complex>(), Complex::from(0.0));
+        assert_eq!(std::iter::empty::<Complex>().product::<Complex>(), Complex::from(1.0));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Complex::from((1.5, -2.0)), Complex::new(1.5, -2));
+        assert_eq!(Complex::new(1, 1) + (0.0, 1.0), Complex::new(1, 2));
+        assert_eq!(Complex::from(7i64), Complex::new(7, 0));
+        assert_eq!(Complex::from(2.5f32), Complex::new(2.5f32, 0));
+    }
+}
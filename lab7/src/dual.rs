@@ -0,0 +1,161 @@
+use crate::algebra::{impl_algebra, Algebra};
+use crate::num::{Float, Num};
+
+// --- Definirea Structurii Dual ---
+
+/// Numar dual `a + bε` cu `ε^2 = 0` (implicit peste `f64`).
+///
+/// Folosit pentru diferentiere automata in mod direct: daca `x = Dual::variable(x0)`,
+/// atunci `f(x)` are `real = f(x0)` si `dual = f'(x0)`.
+#[derive(Debug, Copy, Clone)]
+pub struct Dual<T = f64> {
+    pub real: T,
+    pub dual: T,
+}
+
+impl<T: Num> Dual<T> {
+    pub fn from_parts(real: T, dual: T) -> Self {
+        Dual { real, dual }
+    }
+
+    /// Variabila de derivare `x + 1ε`
+    pub fn variable(x: T) -> Self {
+        Dual::from_parts(x, T::ONE)
+    }
+
+    /// O constanta `c + 0ε` (derivata zero)
+    pub fn constant(c: T) -> Self {
+        Dual::from(c)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Dual::from_parts(self.real, -self.dual)
+    }
+
+    /// `f(a + bε) = f(a) + f'(a) b ε`; baza tuturor functiilor de mai jos
+    fn chain(&self, value: T, derivative: T) -> Self {
+        Dual::from_parts(value, derivative * self.dual)
+    }
+
+    pub fn powi(&self, n: i32) -> Self {
+        let mut result = Dual::from(T::ONE);
+        for _ in 0..n.unsigned_abs() {
+            result *= *self;
+        }
+        if n < 0 {
+            Dual::from(T::ONE) / result
+        } else {
+            result
+        }
+    }
+}
+
+impl<T: Num> Algebra for Dual<T> {
+    type Scalar = T;
+
+    fn product(self, rhs: Self) -> Self {
+        // (a+bε)(c+dε) = ac + (ad+bc)ε
+        Dual {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+
+    fn quotient(self, rhs: Self) -> Self {
+        // (a+bε)/(c+dε) = a/c + (bc-ad)/c^2 ε
+        Dual {
+            real: self.real / rhs.real,
+            dual: (self.dual * rhs.real - self.real * rhs.dual) / (rhs.real * rhs.real),
+        }
+    }
+}
+
+impl_algebra!(Dual { real: "", dual: "ε" });
+
+// --- Functii elementare (doar float-uri) ---
+
+impl<T: Float> Dual<T> {
+    pub fn exp(&self) -> Self {
+        let e = self.real.exp();
+        self.chain(e, e)
+    }
+
+    pub fn ln(&self) -> Self {
+        self.chain(self.real.ln(), T::ONE / self.real)
+    }
+
+    pub fn sqrt(&self) -> Self {
+        let s = self.real.sqrt();
+        self.chain(s, T::ONE / (s + s))
+    }
+
+    pub fn sin(&self) -> Self {
+        self.chain(self.real.sin(), self.real.cos())
+    }
+
+    pub fn cos(&self) -> Self {
+        self.chain(self.real.cos(), -self.real.sin())
+    }
+
+    pub fn tan(&self) -> Self {
+        let c = self.real.cos();
+        self.chain(self.real.sin() / c, T::ONE / (c * c))
+    }
+
+    /// `x^p` pentru exponent real
+    pub fn powf(&self, p: T) -> Self {
+        let value = (p * self.real.ln()).exp();
+        self.chain(value, p * value / self.real)
+    }
+}
+
+/// Valoarea si derivata lui `f` in `x`, prin diferentiere automata
+pub fn derivative<T: Num, F>(f: F, x: T) -> (T, T)
+where
+    F: Fn(Dual<T>) -> Dual<T>,
+{
+    let out = f(Dual::variable(x));
+    (out.real, out.dual)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_display() {
+        let x = Dual::from_parts(3.0, 1.0);
+        assert_eq!(x * x, Dual::from_parts(9.0, 6.0));
+        assert_eq!((x * x) / x, x);
+        assert_eq!(x + 2.0, Dual::from_parts(5.0, 1.0));
+        assert_eq!(Dual::from_parts(3, -1).to_string(), "3-1ε");
+        assert_eq!(Dual::from_parts(0, 2).powi(2).to_string(), "0");
+    }
+
+    #[test]
+    fn polynomial_derivative() {
+        // f(x) = 3x^3 - 2x + 1, f'(x) = 9x^2 - 2
+        let (value, slope) = derivative(|x| x.powi(3) * 3 - x * 2 + 1, 2);
+        assert_eq!((value, slope), (21, 34));
+    }
+
+    #[test]
+    fn elementary_function_derivatives() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        let x0 = 0.8;
+
+        let (_, d) = derivative(|x| x.sin() * x.exp(), x0);
+        assert!(close(d, x0.cos() * x0.exp() + x0.sin() * x0.exp()));
+
+        let (_, d) = derivative(|x| (x * x + 1.0).ln(), x0);
+        assert!(close(d, 2.0 * x0 / (x0 * x0 + 1.0)));
+
+        let (_, d) = derivative(|x| x.sqrt() / x.cos(), x0);
+        let expected = (0.5 / x0.sqrt()) / x0.cos() + x0.sqrt() * x0.sin() / (x0.cos() * x0.cos());
+        assert!(close(d, expected));
+
+        let (v, d) = derivative(|x| x.powf(2.5) + x.tan() + x.powi(-1), x0);
+        assert!(close(v, x0.powf(2.5) + x0.tan() + 1.0 / x0));
+        assert!(close(d, 2.5 * x0.powf(1.5) + 1.0 / (x0.cos() * x0.cos()) - 1.0 / (x0 * x0)));
+    }
+}
@@ -0,0 +1,508 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::f64::consts::{E, PI};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::complex::Complex;
+
+// --- Eroarea evaluatorului ---
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// Eroare de sintaxa la pozitia (in caractere) `pos`
+    Parse { pos: usize, message: String },
+    UnknownVariable(String),
+    UnknownFunction(String),
+    /// Functie apelata cu un numar gresit de argumente
+    Arity {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    DivisionByZero,
+    /// Numele este rezervat (constanta) si nu poate fi reasignat
+    ReadOnly(String),
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            EvalError::Parse { pos, message } => write!(f, "parse error at column {}: {}", pos + 1, message),
+            EvalError::UnknownVariable(name) => write!(f, "unknown variable '{}'", name),
+            EvalError::UnknownFunction(name) => write!(f, "unknown function '{}'", name),
+            EvalError::Arity {
+                name,
+                expected,
+                found,
+            } => write!(f, "{}() takes {} argument(s), got {}", name, expected, found),
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+            EvalError::ReadOnly(name) => write!(f, "cannot assign to constant '{}'", name),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+// --- Arborele sintactic ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(Complex),
+    Var(String),
+    Neg(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+/// O linie din calculator: o atribuire `x = expr` sau o expresie simpla
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Assign(String, Expr),
+    Expr(Expr),
+}
+
+// --- Tokenizer ---
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    /// Literal imaginar, ex: `2i`, `1.5e3j`
+    Imag(f64),
+    Ident(String),
+    Op(char),
+    LParen,
+    RParen,
+    Comma,
+    Assign,
+}
+
+fn parse_error(pos: usize, message: impl Into<String>) -> EvalError {
+    EvalError::Parse {
+        pos,
+        message: message.into(),
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, EvalError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let c = chars[pos];
+        let start = pos;
+        if c.is_whitespace() {
+            pos += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            while pos < chars.len() && (chars[pos].is_ascii_digit() || chars[pos] == '.') {
+                pos += 1;
+            }
+            // Exponent doar daca urmeaza cifre: `2e3`, `2e-3` (dar nu `2e`)
+            if pos < chars.len() && matches!(chars[pos], 'e' | 'E') {
+                let mut next = pos + 1;
+                if next < chars.len() && matches!(chars[next], '+' | '-') {
+                    next += 1;
+                }
+                if next < chars.len() && chars[next].is_ascii_digit() {
+                    pos = next;
+                    while pos < chars.len() && chars[pos].is_ascii_digit() {
+                        pos += 1;
+                    }
+                }
+            }
+            let text: String = chars[start..pos].iter().collect();
+            let value: f64 = text
+                .parse()
+                .map_err(|_| parse_error(start, format!("invalid number '{}'", text)))?;
+
+            let is_imag = pos < chars.len()
+                && matches!(chars[pos], 'i' | 'j')
+                && !chars.get(pos + 1).is_some_and(|c| c.is_alphanumeric() || *c == '_');
+            if is_imag {
+                pos += 1;
+                tokens.push((start, Token::Imag(value)));
+            } else {
+                tokens.push((start, Token::Number(value)));
+            }
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            while pos < chars.len() && (chars[pos].is_alphanumeric() || chars[pos] == '_') {
+                pos += 1;
+            }
+            tokens.push((start, Token::Ident(chars[start..pos].iter().collect())));
+            continue;
+        }
+
+        let token = match c {
+            '+' | '-' | '*' | '/' | '^' => Token::Op(c),
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '=' => Token::Assign,
+            _ => return Err(parse_error(start, format!("unexpected character '{}'", c))),
+        };
+        tokens.push((start, token));
+        pos += 1;
+    }
+    Ok(tokens)
+}
+
+// --- Parser (descendent recursiv) ---
+//
+// statement := IDENT '=' expr | expr
+// expr      := term (('+' | '-') term)*
+// term      := unary (('*' | '/') unary)*
+// unary     := ('+' | '-') unary | power
+// power     := primary ('^' unary)?          (asociativ la dreapta)
+// primary   := NUMBER | IMAG | IDENT | IDENT '(' args ')' | '(' expr ')'
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    idx: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.idx).map(|(_, t)| t)
+    }
+
+    fn pos(&self) -> usize {
+        self.tokens.get(self.idx).map_or(self.end, |(p, _)| *p)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.idx).map(|(_, t)| t.clone());
+        self.idx += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<(), EvalError> {
+        let pos = self.pos();
+        match self.next() {
+            Some(t) if t == expected => Ok(()),
+            _ => Err(parse_error(pos, format!("expected {}", what))),
+        }
+    }
+
+    fn statement(&mut self) -> Result<Statement, EvalError> {
+        if let (Some((_, Token::Ident(name))), Some((_, Token::Assign))) =
+            (self.tokens.first(), self.tokens.get(1))
+        {
+            let name = name.clone();
+            self.idx = 2;
+            return Ok(Statement::Assign(name, self.expr()?));
+        }
+        Ok(Statement::Expr(self.expr()?))
+    }
+
+    fn expr(&mut self) -> Result<Expr, EvalError> {
+        let mut lhs = self.term()?;
+        while let Some(Token::Op(op @ ('+' | '-'))) = self.peek() {
+            let op = if *op == '+' { BinOp::Add } else { BinOp::Sub };
+            self.idx += 1;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.term()?));
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Expr, EvalError> {
+        let mut lhs = self.unary()?;
+        while let Some(Token::Op(op @ ('*' | '/'))) = self.peek() {
+            let op = if *op == '*' { BinOp::Mul } else { BinOp::Div };
+            self.idx += 1;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, EvalError> {
+        match self.peek() {
+            Some(Token::Op('-')) => {
+                self.idx += 1;
+                Ok(Expr::Neg(Box::new(self.unary()?)))
+            }
+            Some(Token::Op('+')) => {
+                self.idx += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Expr, EvalError> {
+        let base = self.primary()?;
+        if let Some(Token::Op('^')) = self.peek() {
+            self.idx += 1;
+            return Ok(Expr::Binary(BinOp::Pow, Box::new(base), Box::new(self.unary()?)));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Expr, EvalError> {
+        let pos = self.pos();
+        match self.next() {
+            Some(Token::Number(x)) => Ok(Expr::Number(Complex::from(x))),
+            Some(Token::Imag(x)) => Ok(Expr::Number(Complex::new(0, x))),
+            Some(Token::Ident(name)) => {
+                if self.peek() != Some(&Token::LParen) {
+                    return Ok(Expr::Var(name));
+                }
+                self.idx += 1;
+                let mut args = Vec::new();
+                if self.peek() != Some(&Token::RParen) {
+                    args.push(self.expr()?);
+                    while self.peek() == Some(&Token::Comma) {
+                        self.idx += 1;
+                        args.push(self.expr()?);
+                    }
+                }
+                self.expect(Token::RParen, "')' after function arguments")?;
+                Ok(Expr::Call(name, args))
+            }
+            Some(Token::LParen) => {
+                let inner = self.expr()?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(inner)
+            }
+            Some(_) => Err(parse_error(pos, "expected a number, variable or '('")),
+            None => Err(parse_error(pos, "unexpected end of input")),
+        }
+    }
+}
+
+/// Parseaza o linie (atribuire sau expresie) intr-un `Statement`
+pub fn parse_statement(input: &str) -> Result<Statement, EvalError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens,
+        idx: 0,
+        end: input.chars().count(),
+    };
+    let statement = parser.statement()?;
+    if parser.idx < parser.tokens.len() {
+        return Err(parse_error(parser.pos(), "unexpected trailing input"));
+    }
+    Ok(statement)
+}
+
+// --- Evaluare ---
+
+/// Constantele predefinite; nu pot fi reasignate
+const CONSTANTS: [&str; 3] = ["i", "pi", "e"];
+
+/// Calculatorul: variabilele utilizatorului plus `ans`, ultimul rezultat.
+pub struct Calculator {
+    vars: HashMap<String, Complex>,
+}
+
+impl Default for Calculator {
+    fn default() -> Self {
+        Calculator::new()
+    }
+}
+
+impl Calculator {
+    pub fn new() -> Self {
+        let mut vars = HashMap::new();
+        vars.insert("i".to_string(), Complex::new(0, 1));
+        vars.insert("pi".to_string(), Complex::from(PI));
+        vars.insert("e".to_string(), Complex::from(E));
+        Calculator { vars }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Complex> {
+        self.vars.get(name).copied()
+    }
+
+    /// Evalueaza o linie; rezultatul este salvat si in `ans` (si in variabila, la atribuire)
+    pub fn eval_line(&mut self, line: &str) -> Result<Complex, EvalError> {
+        let value = match parse_statement(line)? {
+            Statement::Assign(name, expr) => {
+                if CONSTANTS.contains(&name.as_str()) {
+                    return Err(EvalError::ReadOnly(name));
+                }
+                let value = self.eval(&expr)?;
+                self.vars.insert(name, value);
+                value
+            }
+            Statement::Expr(expr) => self.eval(&expr)?,
+        };
+        self.vars.insert("ans".to_string(), value);
+        Ok(value)
+    }
+
+    pub fn eval(&self, expr: &Expr) -> Result<Complex, EvalError> {
+        match expr {
+            Expr::Number(z) => Ok(*z),
+            Expr::Var(name) => self
+                .get(name)
+                .ok_or_else(|| EvalError::UnknownVariable(name.clone())),
+            // `0 - z` in loc de `-z`: `-4` trebuie sa aiba `imag = +0`, altfel
+            // `sqrt(-4)` ar cadea pe partea de jos a taieturii si ar da `-2i`
+            Expr::Neg(inner) => Ok(Complex::from(0.0) - self.eval(inner)?),
+            Expr::Binary(op, lhs, rhs) => {
+                let (a, b) = (self.eval(lhs)?, self.eval(rhs)?);
+                match op {
+                    BinOp::Add => Ok(a + b),
+                    BinOp::Sub => Ok(a - b),
+                    BinOp::Mul => Ok(a * b),
+                    BinOp::Div => a.checked_div(b).map_err(|_| EvalError::DivisionByZero),
+                    BinOp::Pow => power(a, b),
+                }
+            }
+            Expr::Call(name, args) => {
+                let values = args
+                    .iter()
+                    .map(|arg| self.eval(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                call(name, &values)
+            }
+        }
+    }
+}
+
+/// `a^b`: exponentii intregi mici folosesc `powi` (exact), restul `powc`.
+/// Zero la o putere cu partea reala negativa este o impartire la zero, ca `1 / 0`.
+fn power(base: Complex, exp: Complex) -> Result<Complex, EvalError> {
+    if base.is_zero() && exp.real < 0.0 {
+        return Err(EvalError::DivisionByZero);
+    }
+    let n = exp.real;
+    if exp.imag == 0.0 && n.fract() == 0.0 && n.abs() <= i32::MAX as f64 {
+        Ok(base.powi(n as i32))
+    } else {
+        Ok(base.powc(exp))
+    }
+}
+
+fn call(name: &str, args: &[Complex]) -> Result<Complex, EvalError> {
+    let unary: Option<fn(&Complex) -> Complex> = match name {
+        "conj" => Some(Complex::conjugate),
+        "abs" => Some(|z| Complex::from(z.abs())),
+        "arg" => Some(|z| Complex::from(z.arg())),
+        "re" => Some(|z| Complex::from(z.real)),
+        "im" => Some(|z| Complex::from(z.imag)),
+        "recip" => Some(Complex::recip),
+        "exp" => Some(Complex::exp),
+        "ln" => Some(Complex::ln),
+        "sqrt" => Some(Complex::sqrt),
+        "sin" => Some(Complex::sin),
+        "cos" => Some(Complex::cos),
+        "tan" => Some(Complex::tan),
+        "sinh" => Some(Complex::sinh),
+        "cosh" => Some(Complex::cosh),
+        "tanh" => Some(Complex::tanh),
+        _ => None,
+    };
+
+    let arity = |expected: usize| {
+        if args.len() == expected {
+            Ok(())
+        } else {
+            Err(EvalError::Arity {
+                name: name.to_string(),
+                expected,
+                found: args.len(),
+            })
+        }
+    };
+
+    if let Some(f) = unary {
+        arity(1)?;
+        if name == "recip" && args[0].is_zero() {
+            return Err(EvalError::DivisionByZero);
+        }
+        return Ok(f(&args[0]));
+    }
+    match name {
+        "pow" => {
+            arity(2)?;
+            power(args[0], args[1])
+        }
+        "polar" => {
+            arity(2)?;
+            Ok(Complex::from_polar(args[0].real, args[1].real))
+        }
+        _ => Err(EvalError::UnknownFunction(name.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(line: &str) -> Result<Complex, EvalError> {
+        Calculator::new().eval_line(line)
+    }
+
+    #[test]
+    fn precedence_and_associativity() {
+        assert_eq!(eval("1 + 2 * 3"), Ok(Complex::from(7.0)));
+        assert_eq!(eval("(1 + 2) * 3"), Ok(Complex::from(9.0)));
+        assert_eq!(eval("2 ^ 3 ^ 2"), Ok(Complex::from(512.0)));
+        assert_eq!(eval("-2 ^ 2"), Ok(Complex::from(-4.0)));
+        assert_eq!(eval("8 / 4 / 2"), Ok(Complex::from(1.0)));
+    }
+
+    #[test]
+    fn complex_literals_and_functions() {
+        assert_eq!(eval("(1+2i) * conj(3-4i) / 2 + exp(i*pi)"), Ok(Complex::new(-3.5, 5)));
+        assert_eq!(eval("2.5e1j"), Ok(Complex::new(0, 25)));
+        assert_eq!(eval("sqrt(-4)"), Ok(Complex::new(0, 2)));
+        assert_eq!(eval("abs(3+4i)"), Ok(Complex::from(5.0)));
+        assert_eq!(eval("polar(2, pi/2)"), Ok(Complex::new(0, 2)));
+        assert_eq!(eval("i^2"), Ok(Complex::from(-1.0)));
+    }
+
+    #[test]
+    fn variables_and_assignment() {
+        let mut calc = Calculator::new();
+        assert_eq!(calc.eval_line("z = 3 - 4i"), Ok(Complex::new(3, -4)));
+        assert_eq!(calc.eval_line("w = z * conj(z)"), Ok(Complex::from(25.0)));
+        assert_eq!(calc.eval_line("ans / 5"), Ok(Complex::from(5.0)));
+        assert_eq!(calc.get("w"), Some(Complex::from(25.0)));
+        assert_eq!(calc.eval_line("pi = 3"), Err(EvalError::ReadOnly("pi".to_string())));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(eval("1 / 0"), Err(EvalError::DivisionByZero));
+        assert_eq!(eval("0 ^ -1"), Err(EvalError::DivisionByZero));
+        assert_eq!(eval("pow(0, -0.5 + i)"), Err(EvalError::DivisionByZero));
+        assert_eq!(eval("0 ^ 2"), Ok(Complex::from(0.0)));
+        assert_eq!(eval("foo + 1"), Err(EvalError::UnknownVariable("foo".to_string())));
+        assert_eq!(eval("foo(1)"), Err(EvalError::UnknownFunction("foo".to_string())));
+        assert_eq!(
+            eval("exp(1, 2)"),
+            Err(EvalError::Arity {
+                name: "exp".to_string(),
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(eval("(1 + 2").unwrap_err().to_string(), "parse error at column 7: expected ')'");
+        assert_eq!(
+            eval("1 $ 2").unwrap_err(),
+            EvalError::Parse {
+                pos: 2,
+                message: "unexpected character '$'".to_string()
+            }
+        );
+        assert!(eval("1 2").is_err());
+    }
+}
@@ -0,0 +1,251 @@
+use crate::complex::Complex;
+use crate::num::Float;
+
+// --- Transformata Fourier rapida ---
+//
+// Conventia de semn: `X[k] = sum_n x[n] e^(-2πi kn/N)`, iar transformata inversa
+// imparte la `N`, deci `ifft(fft(x)) == x`. Lungimile puteri ale lui 2 folosesc
+// radix-2 in-place; orice alta lungime trece prin algoritmul lui Bluestein.
+
+/// Transformata directa, in-place, pentru orice lungime
+pub fn fft<T: Float>(data: &mut [Complex<T>]) {
+    transform(data, false);
+}
+
+/// Transformata inversa, in-place, normalizata cu `1/N`
+pub fn ifft<T: Float>(data: &mut [Complex<T>]) {
+    transform(data, true);
+    let scale = T::ONE / T::from_f64(data.len() as f64);
+    for x in data.iter_mut() {
+        *x *= scale;
+    }
+}
+
+/// Transformata unui semnal real; intoarce doar cele `N/2 + 1` frecvente
+/// nenegative (restul sunt conjugatele lor).
+pub fn rfft<T: Float>(input: &[T]) -> Vec<Complex<T>> {
+    let mut data: Vec<Complex<T>> = input.iter().map(|&x| Complex::from(x)).collect();
+    fft(&mut data);
+    data.truncate(input.len() / 2 + 1);
+    data
+}
+
+/// Inversul lui `rfft`: reconstruieste semnalul real de lungime `n` din
+/// cele `n/2 + 1` frecvente nenegative.
+///
+/// Panica daca `spectrum` nu are exact `n/2 + 1` elemente.
+pub fn irfft<T: Float>(spectrum: &[Complex<T>], n: usize) -> Vec<T> {
+    assert_eq!(spectrum.len(), n / 2 + 1, "irfft: expected n/2 + 1 bins");
+    let mut data = Vec::with_capacity(n);
+    data.extend_from_slice(spectrum);
+    // Simetria hermitiana: X[n-k] = conj(X[k])
+    for k in (1..n + 1 - spectrum.len()).rev() {
+        data.push(spectrum[k].conjugate());
+    }
+    data.truncate(n);
+    ifft(&mut data);
+    data.into_iter().map(|z| z.real).collect()
+}
+
+/// Convolutia liniara `a * b` (lungime `a.len() + b.len() - 1`), calculata prin FFT
+pub fn convolve<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+
+    let mut fa = padded(a, size);
+    let mut fb = padded(b, size);
+    fft(&mut fa);
+    fft(&mut fb);
+    for (x, &y) in fa.iter_mut().zip(&fb) {
+        *x *= y;
+    }
+    ifft(&mut fa);
+    fa.truncate(len);
+    fa
+}
+
+// --- Implementare ---
+
+fn padded<T: Float>(data: &[Complex<T>], size: usize) -> Vec<Complex<T>> {
+    let mut out = data.to_vec();
+    out.resize(size, Complex::from(T::ZERO));
+    out
+}
+
+/// `e^(±iπ num/den)`; semnul minus pentru transformata directa
+fn twiddle<T: Float>(num: usize, den: usize, inverse: bool) -> Complex<T> {
+    let angle = T::PI * T::from_f64(num as f64) / T::from_f64(den as f64);
+    Complex::from_polar(T::ONE, if inverse { angle } else { -angle })
+}
+
+fn transform<T: Float>(data: &mut [Complex<T>], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(data, inverse);
+    } else {
+        bluestein(data, inverse);
+    }
+}
+
+/// Cooley–Tukey iterativ; `data.len()` trebuie sa fie putere a lui 2
+fn radix2<T: Float>(data: &mut [Complex<T>], inverse: bool) {
+    let n = data.len();
+
+    // Permutarea bit-reversal
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                let w = twiddle(2 * k, len, inverse);
+                let even = data[start + k];
+                let odd = data[start + k + half] * w;
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Algoritmul lui Bluestein: rescrie DFT-ul de lungime `n` ca o convolutie
+/// calculata cu radix-2 pe o lungime putere a lui 2 `>= 2n - 1`.
+fn bluestein<T: Float>(data: &mut [Complex<T>], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+
+    // chirp[k] = e^(∓iπ k^2/n); k^2 se reduce modulo 2n pentru precizie
+    let chirp: Vec<Complex<T>> = (0..n)
+        .map(|k| twiddle((k * k) % (2 * n), n, inverse))
+        .collect();
+
+    let mut a = vec![Complex::from(T::ZERO); m];
+    for (k, (&x, &w)) in data.iter().zip(&chirp).enumerate() {
+        a[k] = x * w;
+    }
+
+    let mut b = vec![Complex::from(T::ZERO); m];
+    b[0] = chirp[0].conjugate();
+    for k in 1..n {
+        b[k] = chirp[k].conjugate();
+        b[m - k] = chirp[k].conjugate();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (x, &y) in a.iter_mut().zip(&b) {
+        *x *= y;
+    }
+    radix2(&mut a, true);
+
+    let scale = T::ONE / T::from_f64(m as f64);
+    for (k, out) in data.iter_mut().enumerate() {
+        *out = a[k] * chirp[k] * scale;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num::{Num, Tolerance};
+
+    const TOL: Tolerance = Tolerance::Absolute(1e-9);
+
+    /// DFT-ul naiv O(n^2), folosit ca referinta
+    fn naive_dft(data: &[Complex]) -> Vec<Complex> {
+        let n = data.len();
+        (0..n)
+            .map(|k| {
+                data.iter().enumerate().fold(Complex::from(0.0), |acc, (j, &x)| {
+                    acc + x * twiddle::<f64>((2 * j * k) % (2 * n), n, false)
+                })
+            })
+            .collect()
+    }
+
+    fn signal(n: usize) -> Vec<Complex> {
+        (0..n)
+            .map(|k| Complex::new((k as f64 * 0.7).sin() + 0.25, (k as f64 * 1.3).cos() - k as f64 / 10.0))
+            .collect()
+    }
+
+    fn assert_close(actual: &[Complex], expected: &[Complex]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!(a.approx_eq(e, TOL), "{} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn matches_naive_dft_for_all_lengths() {
+        for n in [1, 2, 4, 8, 64, 3, 5, 6, 12, 17, 100] {
+            let input = signal(n);
+            let mut output = input.clone();
+            fft(&mut output);
+            assert_close(&output, &naive_dft(&input));
+        }
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        for n in [16, 15, 31] {
+            let input = signal(n);
+            let mut data = input.clone();
+            fft(&mut data);
+            ifft(&mut data);
+            assert_close(&data, &input);
+        }
+    }
+
+    #[test]
+    fn real_input_helpers() {
+        for n in [8, 9] {
+            let input: Vec<f64> = (0..n).map(|k| (k as f64 * 0.3).cos() * 2.0 - 0.5).collect();
+            let spectrum = rfft(&input);
+            let full: Vec<Complex> = input.iter().map(|&x| Complex::from(x)).collect();
+            assert_close(&spectrum, &naive_dft(&full)[..n / 2 + 1]);
+
+            let restored = irfft(&spectrum, n);
+            for (a, b) in restored.iter().zip(&input) {
+                assert!(a.approx_eq_tol(*b, TOL));
+            }
+        }
+    }
+
+    #[test]
+    fn convolution_matches_direct_sum() {
+        let a = signal(7);
+        let b = signal(4);
+        let mut expected = vec![Complex::from(0.0); a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                expected[i + j] += x * y;
+            }
+        }
+        assert_close(&convolve(&a, &b), &expected);
+        assert!(convolve(&a, &[]).is_empty());
+    }
+
+    #[test]
+    fn works_for_f32() {
+        let mut data: Vec<Complex<f32>> = (0..6).map(|k| Complex::from(k as f32)).collect();
+        fft(&mut data);
+        assert_eq!(data[0], Complex::from(15.0f32));
+        ifft(&mut data);
+        assert_eq!(data[5], Complex::from(5.0f32));
+    }
+}
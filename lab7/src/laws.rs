@@ -0,0 +1,176 @@
+// --- Suita de teste pentru legile algebrice ale lui `Complex` ---
+//
+// Intrarile sunt generate aleator cu proptest. Legile care sunt exacte in IEEE 754
+// (comutativitatea, identitatile conjugatului) sunt verificate pe orice valori,
+// inclusiv NaN, infinit si subnormale; cele care pierd precizie la rotunjire
+// (asociativitatea, distributivitatea) doar pe valori finite, cu toleranta relativa.
+
+use proptest::num::f64 as f64_strategy;
+use proptest::prelude::*;
+
+use crate::complex::Complex;
+use crate::num::Tolerance;
+
+/// Orice f64: normale, subnormale, zero cu semn, infinit si NaN
+fn any_component() -> impl Strategy<Value = f64> {
+    f64_strategy::ANY
+}
+
+/// Valori finite moderate, plus subnormale (fara depasiri la inmultire)
+fn finite_component() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        4 => -1e3..1e3f64,
+        1 => f64_strategy::SUBNORMAL,
+        1 => Just(0.0),
+        1 => Just(-0.0),
+    ]
+}
+
+/// Orice i32, cu capetele `MIN`/`MAX` (unde `abs` ar depasi) alese des
+fn any_int_component() -> impl Strategy<Value = i32> {
+    prop_oneof![
+        4 => any::<i32>(),
+        1 => Just(i32::MIN),
+        1 => Just(i32::MAX),
+    ]
+}
+
+fn any_complex() -> impl Strategy<Value = Complex> {
+    (any_component(), any_component()).prop_map(|(re, im)| Complex::new(re, im))
+}
+
+fn finite_complex() -> impl Strategy<Value = Complex> {
+    (finite_component(), finite_component()).prop_map(|(re, im)| Complex::new(re, im))
+}
+
+/// Egalitate "ca valori": `0.0 == -0.0`, iar NaN este egal doar cu NaN
+fn same_value(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+fn same(a: Complex, b: Complex) -> bool {
+    same_value(a.real, b.real) && same_value(a.imag, b.imag)
+}
+
+/// Toleranta relativa la marimea operanzilor: pentru `a + b - b` eroarea
+/// absoluta poate fi de ordinul `eps * |b|`, nu `eps * |a|`
+fn close(a: Complex, b: Complex, scale: f64) -> bool {
+    let tol = Tolerance::Absolute(1e-12 * scale.max(1.0));
+    a.approx_eq(&b, tol)
+}
+
+fn magnitude(zs: &[Complex]) -> f64 {
+    zs.iter().map(|z| z.real.abs().max(z.imag.abs())).fold(0.0, f64::max)
+}
+
+proptest! {
+    // --- Comutativitate (exacta) ---
+
+    #[test]
+    fn addition_is_commutative(a in any_complex(), b in any_complex()) {
+        prop_assert!(same(a + b, b + a));
+    }
+
+    #[test]
+    fn multiplication_is_commutative(a in any_complex(), b in any_complex()) {
+        prop_assert!(same(a * b, b * a));
+    }
+
+    // --- Asociativitate si distributivitate (cu toleranta) ---
+
+    #[test]
+    fn addition_is_associative(a in finite_complex(), b in finite_complex(), c in finite_complex()) {
+        let scale = magnitude(&[a, b, c]);
+        prop_assert!(close((a + b) + c, a + (b + c), scale));
+    }
+
+    #[test]
+    fn multiplication_is_associative(a in finite_complex(), b in finite_complex(), c in finite_complex()) {
+        let scale = magnitude(&[a, b, c]).powi(3);
+        prop_assert!(close((a * b) * c, a * (b * c), scale));
+    }
+
+    #[test]
+    fn multiplication_distributes_over_addition(
+        a in finite_complex(),
+        b in finite_complex(),
+        c in finite_complex(),
+    ) {
+        let scale = magnitude(&[a, b, c]).powi(2);
+        prop_assert!(close(a * (b + c), a * b + a * c, scale));
+    }
+
+    // --- Elemente neutre si inverse ---
+
+    #[test]
+    fn identities(z in finite_complex()) {
+        prop_assert!(same(z + 0.0, z));
+        prop_assert!(same(z * 1.0, z));
+        prop_assert!(same(z - z, Complex::new(0, 0)));
+        prop_assert!(same(-(-z), z));
+    }
+
+    #[test]
+    fn division_undoes_multiplication(a in finite_complex(), b in finite_complex()) {
+        prop_assume!(b.abs() > 1e-3);
+        let scale = magnitude(&[a, b]) / b.abs();
+        prop_assert!(close((a * b) / b, a, scale));
+        prop_assert!(close(a / b * b, a, magnitude(&[a, b]).powi(2)));
+    }
+
+    #[test]
+    fn checked_division_rejects_only_zero(a in any_complex(), b in any_complex()) {
+        prop_assert_eq!(a.checked_div(b).is_err(), b.is_zero());
+    }
+
+    // --- Identitatile conjugatului (exacte) ---
+
+    #[test]
+    fn conjugate_is_an_involution(z in any_complex()) {
+        prop_assert!(z.conjugate().conjugate().bitwise_eq(&z));
+    }
+
+    #[test]
+    fn conjugate_distributes(a in any_complex(), b in any_complex()) {
+        prop_assert!(same((a + b).conjugate(), a.conjugate() + b.conjugate()));
+        prop_assert!(same((a * b).conjugate(), a.conjugate() * b.conjugate()));
+    }
+
+    #[test]
+    fn product_with_conjugate_is_real(z in finite_complex()) {
+        let p = z * z.conjugate();
+        prop_assert_eq!(p.imag, 0.0);
+        prop_assert!(same_value(p.real, z.norm_sqr()));
+    }
+
+    // --- Valori speciale ---
+
+    #[test]
+    fn nan_propagates(z in any_complex()) {
+        let nan = Complex::new(f64::NAN, 0);
+        prop_assert!((z + nan).real.is_nan());
+        prop_assert!((z * nan).real.is_nan() || (z * nan).imag.is_nan());
+        prop_assert_ne!(nan, nan);
+    }
+
+    #[test]
+    fn infinity_absorbs_finite_addition(z in finite_complex()) {
+        let inf = Complex::new(f64::INFINITY, f64::NEG_INFINITY);
+        prop_assert!(same(z + inf, inf));
+    }
+
+    // --- Display / FromStr ---
+
+    #[test]
+    fn display_parse_round_trip(z in any_complex()) {
+        let text = z.to_string();
+        let parsed: Complex = text.parse().map_err(|e| TestCaseError::fail(format!("{}", e)))?;
+        prop_assert!(same(parsed, z), "{} -> {:?} -> {:?}", text, z, parsed);
+    }
+
+    #[test]
+    fn display_parse_round_trip_for_integers(re in any_int_component(), im in any_int_component()) {
+        let z = Complex::from_parts(re, im);
+        prop_assert_eq!(z.to_string().parse::<Complex<i32>>(), Ok(z));
+    }
+}
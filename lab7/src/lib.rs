@@ -0,0 +1,60 @@
+// Biblioteca `lab7`: numarul complex generic (plus cuaternioni si numere duale)
+// si trait-ul numeric pe care il folosesc.
+// Binarul din `main.rs` este un calculator interactiv construit peste `expr`.
+
+pub mod algebra;
+pub mod complex;
+pub mod dual;
+pub mod expr;
+pub mod fft;
+#[cfg(test)]
+mod laws;
+pub mod linalg;
+pub mod num;
+pub mod parse;
+pub mod polynomial;
+pub mod quaternion;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod transcendental;
+
+pub use algebra::Algebra;
+pub use complex::{Complex, DivisionByZero};
+pub use dual::Dual;
+pub use expr::{Calculator, EvalError};
+pub use linalg::{ComplexMatrix, ComplexVector, LinalgError, Lu};
+pub use num::{eq_rel, Float, Num, Tolerance};
+pub use parse::{ParseComplexError, ParseComplexErrorKind};
+pub use polynomial::Polynomial;
+pub use quaternion::Quaternion;
+pub use transcendental::PolarDisplay;
+
+// This is a macro that panics if 2 floats are not equal using an epsilon.
+// Cu un al treilea argument (`Tolerance`) foloseste acea toleranta in loc de `eq_rel`.
+#[macro_export]
+macro_rules! assert_eq_rel {
+    ($x:expr, $y: expr) => {
+        let x = $x as f64;
+        let y = $y as f64;
+        let r = $crate::eq_rel(x, y);
+        assert!(r, "{} != {}", x, y);
+    };
+    ($x:expr, $y: expr, $tol: expr) => {
+        let x = $x as f64;
+        let y = $y as f64;
+        let tol: $crate::Tolerance = $tol;
+        let r = $crate::Num::approx_eq_tol(x, y, tol);
+        assert!(r, "{} != {} (tolerance {:?})", x, y, tol);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assert_eq_rel_with_tolerance() {
+        assert_eq_rel!(1.0005, 1);
+        assert_eq_rel!(1e9 + 1.0, 1e9, Tolerance::Relative(1e-6));
+    }
+}
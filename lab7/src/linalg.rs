@@ -0,0 +1,660 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use crate::complex::Complex;
+use crate::num::{Float, Num};
+
+// --- Eroarea pentru operatiile de algebra liniara ---
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinalgError {
+    /// Dimensiunile operanzilor nu se potrivesc, ex: `(2, 3)` inmultit cu `(2, 3)`
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// Operatia cere o matrice patratica
+    NotSquare { rows: usize, cols: usize },
+    /// Matricea este singulara (un pivot este zero)
+    Singular,
+}
+
+impl Display for LinalgError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            LinalgError::DimensionMismatch { left, right } => write!(
+                f,
+                "dimension mismatch: {}x{} and {}x{}",
+                left.0, left.1, right.0, right.1
+            ),
+            LinalgError::NotSquare { rows, cols } => {
+                write!(f, "expected a square matrix, got {}x{}", rows, cols)
+            }
+            LinalgError::Singular => write!(f, "matrix is singular"),
+        }
+    }
+}
+
+impl Error for LinalgError {}
+
+// --- Definirea Structurii ComplexVector ---
+
+#[derive(Debug, Clone)]
+pub struct ComplexVector<T = f64> {
+    data: Vec<Complex<T>>,
+}
+
+impl<T: Num> ComplexVector<T> {
+    pub fn new<C: Into<Complex<T>>>(data: Vec<C>) -> Self {
+        ComplexVector {
+            data: data.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn zeros(len: usize) -> Self {
+        ComplexVector {
+            data: vec![Complex::from(T::ZERO); len],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[Complex<T>] {
+        &self.data
+    }
+
+    /// Produsul scalar hermitian `sum conj(a_k) * b_k` (conjugat in primul argument)
+    pub fn dot(&self, other: &Self) -> Result<Complex<T>, LinalgError> {
+        self.check_len(other)?;
+        Ok(self
+            .data
+            .iter()
+            .zip(&other.data)
+            .fold(Complex::from(T::ZERO), |acc, (a, &b)| acc + a.conjugate() * b))
+    }
+
+    /// Produsul element cu element
+    pub fn hadamard(&self, other: &Self) -> Result<Self, LinalgError> {
+        self.check_len(other)?;
+        Ok(self.zip_with(other, |a, b| a * b))
+    }
+
+    /// Inmultirea cu un scalar
+    pub fn scale<S: Into<Complex<T>>>(&self, factor: S) -> Self {
+        let factor = factor.into();
+        ComplexVector {
+            data: self.data.iter().map(|&x| x * factor).collect(),
+        }
+    }
+
+    fn check_len(&self, other: &Self) -> Result<(), LinalgError> {
+        if self.len() != other.len() {
+            return Err(LinalgError::DimensionMismatch {
+                left: (self.len(), 1),
+                right: (other.len(), 1),
+            });
+        }
+        Ok(())
+    }
+
+    fn zip_with(&self, other: &Self, op: impl Fn(Complex<T>, Complex<T>) -> Complex<T>) -> Self {
+        ComplexVector {
+            data: self.data.iter().zip(&other.data).map(|(&a, &b)| op(a, b)).collect(),
+        }
+    }
+}
+
+impl<T: Float> ComplexVector<T> {
+    /// Norma euclidiana `sqrt(sum |x_k|^2)`
+    pub fn norm(&self) -> T {
+        self.data
+            .iter()
+            .fold(T::ZERO, |acc, x| acc + x.norm_sqr())
+            .sqrt()
+    }
+}
+
+impl<T: Num> PartialEq for ComplexVector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T> Index<usize> for ComplexVector<T> {
+    type Output = Complex<T>;
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.data[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for ComplexVector<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.data[idx]
+    }
+}
+
+// Operatorii panica la dimensiuni diferite; variantele fara panica sunt metodele
+impl<T: Num> Add for &ComplexVector<T> {
+    type Output = ComplexVector<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_len(rhs).unwrap_or_else(|e| panic!("{}", e));
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T: Num> Sub for &ComplexVector<T> {
+    type Output = ComplexVector<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_len(rhs).unwrap_or_else(|e| panic!("{}", e));
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T: Num> Display for ComplexVector<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_row(f, &self.data)
+    }
+}
+
+fn write_row<T: Num>(f: &mut Formatter<'_>, row: &[Complex<T>]) -> FmtResult {
+    write!(f, "[")?;
+    for (idx, x) in row.iter().enumerate() {
+        if idx > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", x)?;
+    }
+    write!(f, "]")
+}
+
+// --- Definirea Structurii ComplexMatrix ---
+
+/// Matrice complexa densa, stocata pe linii (row-major)
+#[derive(Debug, Clone)]
+pub struct ComplexMatrix<T = f64> {
+    rows: usize,
+    cols: usize,
+    data: Vec<Complex<T>>,
+}
+
+impl<T: Num> ComplexMatrix<T> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        ComplexMatrix {
+            rows,
+            cols,
+            data: vec![Complex::from(T::ZERO); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut m = ComplexMatrix::zeros(n, n);
+        for k in 0..n {
+            m[(k, k)] = Complex::from(T::ONE);
+        }
+        m
+    }
+
+    /// Construieste matricea din linii; toate liniile trebuie sa aiba aceeasi lungime
+    pub fn from_rows<C: Into<Complex<T>>>(rows: Vec<Vec<C>>) -> Result<Self, LinalgError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let n_rows = rows.len();
+        let mut data = Vec::with_capacity(n_rows * cols);
+        for row in rows {
+            if row.len() != cols {
+                return Err(LinalgError::DimensionMismatch {
+                    left: (1, cols),
+                    right: (1, row.len()),
+                });
+            }
+            data.extend(row.into_iter().map(Into::into));
+        }
+        Ok(ComplexMatrix {
+            rows: n_rows,
+            cols,
+            data,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, r: usize) -> &[Complex<T>] {
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut out = ComplexMatrix::zeros(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                out[(c, r)] = self[(r, c)];
+            }
+        }
+        out
+    }
+
+    /// Transpusa conjugata (adjuncta hermitiana) `A^H`
+    pub fn conjugate_transpose(&self) -> Self {
+        let mut out = self.transpose();
+        for x in out.data.iter_mut() {
+            *x = x.conjugate();
+        }
+        out
+    }
+
+    /// Produsul element cu element
+    pub fn hadamard(&self, other: &Self) -> Result<Self, LinalgError> {
+        self.check_same_shape(other)?;
+        Ok(self.zip_with(other, |a, b| a * b))
+    }
+
+    /// Produsul matriceal `self * other`
+    pub fn matmul(&self, other: &Self) -> Result<Self, LinalgError> {
+        if self.cols != other.rows {
+            return Err(self.mismatch(other));
+        }
+        let mut out = ComplexMatrix::zeros(self.rows, other.cols);
+        for r in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(r, k)];
+                for c in 0..other.cols {
+                    out[(r, c)] += a * other[(k, c)];
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Produsul matrice-vector `self * v`
+    pub fn mul_vector(&self, v: &ComplexVector<T>) -> Result<ComplexVector<T>, LinalgError> {
+        if self.cols != v.len() {
+            return Err(LinalgError::DimensionMismatch {
+                left: (self.rows, self.cols),
+                right: (v.len(), 1),
+            });
+        }
+        Ok(ComplexVector {
+            data: (0..self.rows)
+                .map(|r| {
+                    self.row(r)
+                        .iter()
+                        .zip(&v.data)
+                        .fold(Complex::from(T::ZERO), |acc, (&a, &b)| acc + a * b)
+                })
+                .collect(),
+        })
+    }
+
+    fn mismatch(&self, other: &Self) -> LinalgError {
+        LinalgError::DimensionMismatch {
+            left: (self.rows, self.cols),
+            right: (other.rows, other.cols),
+        }
+    }
+
+    fn check_same_shape(&self, other: &Self) -> Result<(), LinalgError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(self.mismatch(other));
+        }
+        Ok(())
+    }
+
+    fn check_square(&self) -> Result<(), LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(())
+    }
+
+    fn zip_with(&self, other: &Self, op: impl Fn(Complex<T>, Complex<T>) -> Complex<T>) -> Self {
+        ComplexMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().zip(&other.data).map(|(&a, &b)| op(a, b)).collect(),
+        }
+    }
+}
+
+// --- Descompunerea LU si rezolvarea sistemelor (doar float-uri) ---
+
+/// Descompunerea `P A = L U` cu pivotare partiala.
+///
+/// `L` (cu diagonala 1, nestocata) si `U` sunt pastrate impreuna in `lu`.
+#[derive(Debug, Clone)]
+pub struct Lu<T = f64> {
+    lu: ComplexMatrix<T>,
+    perm: Vec<usize>,
+    odd_swaps: bool,
+}
+
+impl<T: Float> Lu<T> {
+    /// Matricea inferior triunghiulara `L`
+    pub fn l(&self) -> ComplexMatrix<T> {
+        let n = self.lu.rows;
+        let mut l = ComplexMatrix::identity(n);
+        for r in 0..n {
+            for c in 0..r {
+                l[(r, c)] = self.lu[(r, c)];
+            }
+        }
+        l
+    }
+
+    /// Matricea superior triunghiulara `U`
+    pub fn u(&self) -> ComplexMatrix<T> {
+        let n = self.lu.rows;
+        let mut u = ComplexMatrix::zeros(n, n);
+        for r in 0..n {
+            for c in r..n {
+                u[(r, c)] = self.lu[(r, c)];
+            }
+        }
+        u
+    }
+
+    /// Permutarea liniilor: linia `k` din `P A` este linia `perm()[k]` din `A`
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn determinant(&self) -> Complex<T> {
+        let det = (0..self.lu.rows).fold(Complex::from(T::ONE), |acc, k| acc * self.lu[(k, k)]);
+        if self.odd_swaps {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Rezolva `A x = b` prin substitutie inainte si inapoi
+    pub fn solve(&self, b: &ComplexVector<T>) -> Result<ComplexVector<T>, LinalgError> {
+        let n = self.lu.rows;
+        if b.len() != n {
+            return Err(LinalgError::DimensionMismatch {
+                left: (n, n),
+                right: (b.len(), 1),
+            });
+        }
+
+        // L y = P b
+        let mut x: Vec<Complex<T>> = self.perm.iter().map(|&p| b[p]).collect();
+        for r in 0..n {
+            for c in 0..r {
+                let term = self.lu[(r, c)] * x[c];
+                x[r] -= term;
+            }
+        }
+        // U x = y
+        for r in (0..n).rev() {
+            for c in r + 1..n {
+                let term = self.lu[(r, c)] * x[c];
+                x[r] -= term;
+            }
+            x[r] /= self.lu[(r, r)];
+        }
+        Ok(ComplexVector { data: x })
+    }
+}
+
+impl<T: Float> ComplexMatrix<T> {
+    /// Descompunerea LU cu pivotare partiala (pivotul de modul maxim pe coloana).
+    ///
+    /// Matricea este considerata singulara cand un pivot are modulul cel mult
+    /// `n·ε·max|a_ij|`: sub acest prag pivotul este zgomot de rotunjire, iar solutia ar fi inutila.
+    pub fn lu(&self) -> Result<Lu<T>, LinalgError> {
+        self.check_square()?;
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut odd_swaps = false;
+        let largest = self.data.iter().map(Complex::abs).fold(T::ZERO, |m, x| if x > m { x } else { m });
+        let threshold = T::from_f64(n as f64) * T::EPSILON * largest;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| {
+                    let (a, b) = (lu[(a, k)].abs(), lu[(b, k)].abs());
+                    a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+            if lu[(pivot, k)].abs() <= threshold {
+                return Err(LinalgError::Singular);
+            }
+            if pivot != k {
+                for c in 0..n {
+                    lu.data.swap(k * n + c, pivot * n + c);
+                }
+                perm.swap(k, pivot);
+                odd_swaps = !odd_swaps;
+            }
+
+            let diag = lu[(k, k)];
+            for r in k + 1..n {
+                let factor = lu[(r, k)] / diag;
+                lu[(r, k)] = factor;
+                for c in k + 1..n {
+                    let upper = lu[(k, c)];
+                    lu[(r, c)] -= factor * upper;
+                }
+            }
+        }
+
+        Ok(Lu { lu, perm, odd_swaps })
+    }
+
+    /// Determinantul; zero pentru o matrice singulara
+    pub fn determinant(&self) -> Result<Complex<T>, LinalgError> {
+        match self.lu() {
+            Ok(lu) => Ok(lu.determinant()),
+            Err(LinalgError::Singular) => Ok(Complex::from(T::ZERO)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Rezolva sistemul `self * x = b`
+    pub fn solve(&self, b: &ComplexVector<T>) -> Result<ComplexVector<T>, LinalgError> {
+        self.lu()?.solve(b)
+    }
+
+    /// Inversa matricii, coloana cu coloana din descompunerea LU
+    pub fn inverse(&self) -> Result<Self, LinalgError> {
+        let lu = self.lu()?;
+        let n = self.rows;
+        let mut out = ComplexMatrix::zeros(n, n);
+        for c in 0..n {
+            let mut e = ComplexVector::zeros(n);
+            e[c] = Complex::from(T::ONE);
+            let col = lu.solve(&e)?;
+            for r in 0..n {
+                out[(r, c)] = col[r];
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl<T: Num> PartialEq for ComplexMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows && self.cols == other.cols && self.data == other.data
+    }
+}
+
+impl<T> Index<(usize, usize)> for ComplexMatrix<T> {
+    type Output = Complex<T>;
+    fn index(&self, (r, c): (usize, usize)) -> &Self::Output {
+        assert!(r < self.rows && c < self.cols, "index ({}, {}) out of bounds", r, c);
+        &self.data[r * self.cols + c]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for ComplexMatrix<T> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut Self::Output {
+        assert!(r < self.rows && c < self.cols, "index ({}, {}) out of bounds", r, c);
+        &mut self.data[r * self.cols + c]
+    }
+}
+
+// Operatorii panica la dimensiuni nepotrivite; variantele fara panica sunt metodele
+impl<T: Num> Add for &ComplexMatrix<T> {
+    type Output = ComplexMatrix<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_same_shape(rhs).unwrap_or_else(|e| panic!("{}", e));
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T: Num> Sub for &ComplexMatrix<T> {
+    type Output = ComplexMatrix<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_same_shape(rhs).unwrap_or_else(|e| panic!("{}", e));
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T: Num> Mul for &ComplexMatrix<T> {
+    type Output = ComplexMatrix<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.matmul(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Num> Mul<&ComplexVector<T>> for &ComplexMatrix<T> {
+    type Output = ComplexVector<T>;
+    fn mul(self, rhs: &ComplexVector<T>) -> Self::Output {
+        self.mul_vector(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Num> Display for ComplexMatrix<T> {
+    /// O linie pe rand, ex: `[1+2i, 3]`
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for r in 0..self.rows {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            write_row(f, self.row(r))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: Vec<Vec<Complex>>) -> ComplexMatrix {
+        ComplexMatrix::from_rows(rows).unwrap()
+    }
+
+    fn sample() -> ComplexMatrix {
+        matrix(vec![
+            vec![Complex::new(2, 1), Complex::new(0, -1), Complex::new(1, 0)],
+            vec![Complex::new(1, 0), Complex::new(3, 2), Complex::new(0, 1)],
+            vec![Complex::new(0, 2), Complex::new(1, 1), Complex::new(4, 0)],
+        ])
+    }
+
+    #[test]
+    fn vector_products() {
+        let a = ComplexVector::new(vec![Complex::new(1, 1), Complex::new(0, 2)]);
+        let b = ComplexVector::new(vec![Complex::new(3, 0), Complex::new(1, -1)]);
+        // conj(1+i)*3 + conj(2i)*(1-i) = (3-3i) + (-2-2i)
+        assert_eq!(a.dot(&b), Ok(Complex::new(1, -5)));
+        assert_eq!(a.hadamard(&b), Ok(ComplexVector::new(vec![Complex::new(3, 3), Complex::new(2, 2)])));
+        assert_eq!(&a + &b, ComplexVector::new(vec![Complex::new(4, 1), Complex::new(1, 1)]));
+        assert!((a.norm() - 6f64.sqrt()).abs() < 1e-12);
+        assert!(a.dot(&ComplexVector::zeros(3)).is_err());
+    }
+
+    #[test]
+    fn matrix_products_and_transpose() {
+        let a = sample();
+        let id = ComplexMatrix::identity(3);
+        assert_eq!(&a * &id, a);
+        assert_eq!(a.conjugate_transpose()[(0, 2)], Complex::new(0, -2));
+        assert_eq!(a.conjugate_transpose().conjugate_transpose(), a);
+        // (AB)^H = B^H A^H
+        let b = a.hadamard(&a).unwrap();
+        assert_eq!((&a * &b).conjugate_transpose(), &b.conjugate_transpose() * &a.conjugate_transpose());
+
+        let wide = matrix(vec![vec![Complex::new(1, 0), Complex::new(2, 0)]]);
+        assert_eq!(
+            wide.matmul(&wide),
+            Err(LinalgError::DimensionMismatch { left: (1, 2), right: (1, 2) })
+        );
+        assert_eq!(wide.determinant(), Err(LinalgError::NotSquare { rows: 1, cols: 2 }));
+    }
+
+    #[test]
+    fn lu_reconstructs_permuted_matrix() {
+        let a = sample();
+        let lu = a.lu().unwrap();
+        let product = &lu.l() * &lu.u();
+        for (k, &p) in lu.perm().iter().enumerate() {
+            assert_eq!(product.row(k), a.row(p));
+        }
+    }
+
+    #[test]
+    fn determinant_and_inverse() {
+        let a = matrix(vec![
+            vec![Complex::new(1, 1), Complex::new(2, 0)],
+            vec![Complex::new(0, 1), Complex::new(1, -1)],
+        ]);
+        // (1+i)(1-i) - 2i = 2 - 2i
+        assert_eq!(a.determinant(), Ok(Complex::new(2, -2)));
+        assert_eq!(&a * &a.inverse().unwrap(), ComplexMatrix::identity(2));
+
+        let singular = matrix(vec![
+            vec![Complex::new(1, 1), Complex::new(2, 2)],
+            vec![Complex::new(1, 0), Complex::new(2, 0)],
+        ]);
+        assert_eq!(singular.determinant(), Ok(Complex::from(0.0)));
+        assert_eq!(singular.solve(&ComplexVector::zeros(2)), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn rounding_noise_pivot_is_singular() {
+        let rows = [[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]];
+        let a = matrix(rows.iter().map(|row| row.iter().map(|&x| Complex::from(x)).collect()).collect());
+        assert_eq!(a.solve(&ComplexVector::new(vec![1.0, 2.0, 3.0])), Err(LinalgError::Singular));
+        assert!(a.determinant().unwrap().bitwise_eq(&Complex::from(0.0)));
+
+        // Pragul este relativ la marimea elementelor, deci o matrice mica dar buna ramane inversabila
+        let mut tiny = ComplexMatrix::identity(3);
+        for k in 0..3 {
+            tiny[(k, k)] = Complex::from(1e-200);
+        }
+        assert!(tiny.lu().is_ok());
+    }
+
+    #[test]
+    fn solves_ac_phasor_circuit() {
+        // Doua ochiuri: impedantele in ohmi, sursa de 10∠0 V in primul ochi
+        let z = matrix(vec![
+            vec![Complex::new(10, 5), Complex::new(-5, 0)],
+            vec![Complex::new(-5, 0), Complex::new(8, -3)],
+        ]);
+        let v = ComplexVector::new(vec![10.0, 0.0]);
+        let currents = z.solve(&v).unwrap();
+        assert_eq!(&z * &currents, v);
+    }
+}
@@ -1,236 +1,78 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-
-// --- Functia de comparare (mutata aici pentru a fi globala) ---
-// Functie helper pentru a compara f64 cu o marja de eroare
-fn eq_rel(x: f64, y: f64) -> bool {
-    (x - y).abs() < 0.001
-}
-
-// --- Definirea Structurii Complex ---
-
-#[derive(Debug, Copy, Clone)]
-pub struct Complex {
-    pub real: f64,
-    pub imag: f64,
-}
-
-// --- Implementarea functiilor asociate ---
-
-impl Complex {
-    /// Creeaza un numar complex nou din tipuri generice care pot fi convertite in f64
-    pub fn new<T, U>(real: T, imag: U) -> Self
-    where
-        T: Into<f64>,
-        U: Into<f64>,
-    {
-        Complex {
-            real: real.into(),
-            imag: imag.into(),
-        }
-    }
-
-    /// Returneaza conjugatul numarului complex
-    pub fn conjugate(&self) -> Self {
-        Complex {
-            real: self.real,
-            imag: -self.imag,
-        }
-    }
-}
-
-// --- Implementarea PartialEq pentru assert_eq! ---
-
-impl PartialEq for Complex {
-    /// Compara doua numere complexe folosind functia `eq_rel`
-    fn eq(&self, other: &Self) -> bool {
-        eq_rel(self.real, other.real) && eq_rel(self.imag, other.imag)
-    }
-}
-
-// --- Implementarea Trait-ului `From` ---
-
-impl From<i32> for Complex {
-    fn from(n: i32) -> Self {
-        Complex {
-            real: n.into(),
-            imag: 0.0,
-        }
-    }
-}
-
-impl From<f64> for Complex {
-    fn from(n: f64) -> Self {
-        Complex {
-            real: n,
-            imag: 0.0,
-        }
-    }
-}
-
-// --- Implementarea Operatorilor (Generic) ---
-
-// impl Add<T> for Complex
-impl<T: Into<Complex>> Add<T> for Complex {
-    type Output = Complex;
-    fn add(self, rhs: T) -> Self::Output {
-        let rhs = rhs.into();
-        Complex {
-            real: self.real + rhs.real,
-            imag: self.imag + rhs.imag,
-        }
-    }
+use std::env;
+use std::io::{self, BufRead, Write};
+
+use lab7::{Calculator, Complex};
+
+// --- Calculator pentru numere complexe ---
+//
+// `lab7 "(1+2i) * conj(3-4i)"` evalueaza argumentele si iese;
+// fara argumente porneste un REPL (scrie 'stop' sau EOF pentru a iesi).
+
+/// Zgomotul de rotunjire (ex: `1.2e-16i` din `exp(i*pi)`) se afiseaza ca 0: doar componentele
+/// sub `1e-12` in valoare absoluta si neglijabile fata de cealalta, ca `1e20+1i` sa ramana intreg
+fn tidy(z: Complex) -> Complex {
+    const NOISE: f64 = 1e-12;
+    let scale = z.real.abs().max(z.imag.abs()) * NOISE;
+    let snap = |x: f64| if x.abs() < NOISE && x.abs() < scale { 0.0 } else { x };
+    Complex::new(snap(z.real), snap(z.imag))
 }
 
-// impl Sub<T> for Complex
-impl<T: Into<Complex>> Sub<T> for Complex {
-    type Output = Complex;
-    fn sub(self, rhs: T) -> Self::Output {
-        let rhs = rhs.into();
-        Complex {
-            real: self.real - rhs.real,
-            imag: self.imag - rhs.imag,
+/// Evalueaza o linie si afiseaza rezultatul; intoarce `false` la eroare
+fn run_line(calc: &mut Calculator, line: &str) -> bool {
+    match calc.eval_line(line) {
+        Ok(value) => {
+            println!("{}", tidy(value));
+            true
         }
-    }
-}
-
-// impl Mul<T> for Complex
-impl<T: Into<Complex>> Mul<T> for Complex {
-    type Output = Complex;
-    fn mul(self, rhs: T) -> Self::Output {
-        let rhs = rhs.into();
-        // (a+bi)(c+di) = (ac-bd) + (ad+bc)i
-        let real_part = self.real * rhs.real - self.imag * rhs.imag;
-        let imag_part = self.real * rhs.imag + self.imag * rhs.real;
-        Complex {
-            real: real_part,
-            imag: imag_part,
-        }
-    }
-}
-
-// impl Neg for Complex
-impl Neg for Complex {
-    type Output = Complex;
-    fn neg(self) -> Self::Output {
-        Complex {
-            real: -self.real,
-            imag: -self.imag,
+        Err(e) => {
+            println!("Error: {}", e);
+            false
         }
     }
 }
 
-// --- Implementarea `Display` ---
-
-impl Display for Complex {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let real = self.real;
-        let imag = self.imag;
-        
-        let real_is_zero = eq_rel(real, 0.0);
-        let imag_is_zero = eq_rel(imag, 0.0);
-
-        match (real_is_zero, imag_is_zero) {
-            // Caz: 0+0i -> "0"
-            (true, true) => write!(f, "0"),
-            
-            // Caz: 7+0i -> "7"
-            (false, true) => write!(f, "{}", real),
-            
-            // Caz: 0+5i -> "5i"
-            (true, false) => write!(f, "{}i", imag),
-            
-            // Caz: 1+2i sau 1-2i
-            (false, false) => {
-                if imag < 0.0 {
-                    // "1-2i" (folosim abs() pentru a evita "1+-2i")
-                    write!(f, "{}-{}i", real, imag.abs())
-                } else {
-                    // "1+2i"
-                    write!(f, "{}+{}i", real, imag)
+fn run_interactive(calc: &mut Calculator) {
+    let stdin = io::stdin();
+    let mut handle = stdin.lock();
+    let mut buffer = String::new();
+
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        buffer.clear();
+        match handle.read_line(&mut buffer) {
+            Ok(0) => break, // EOF
+            Ok(_) => {
+                let trimmed = buffer.trim();
+                if trimmed == "stop" {
+                    break;
+                }
+                if !trimmed.is_empty() {
+                    run_line(calc, trimmed);
                 }
             }
+            Err(e) => {
+                println!("Error reading line: {}", e);
+                break;
+            }
         }
     }
 }
 
+fn main() {
+    let mut calc = Calculator::new();
+    let args: Vec<String> = env::args().skip(1).collect();
 
-// --- BONUS: Operatori Assign ---
-
-impl<T: Into<Complex>> AddAssign<T> for Complex {
-    fn add_assign(&mut self, rhs: T) {
-        let rhs = rhs.into();
-        self.real += rhs.real;
-        self.imag += rhs.imag;
+    if args.is_empty() {
+        run_interactive(&mut calc);
+        return;
     }
-}
 
-impl<T: Into<Complex>> SubAssign<T> for Complex {
-    fn sub_assign(&mut self, rhs: T) {
-        let rhs = rhs.into();
-        self.real -= rhs.real;
-        self.imag -= rhs.imag;
+    let mut ok = true;
+    for expr in &args {
+        ok &= run_line(&mut calc, expr);
     }
-}
-
-impl<T: Into<Complex>> MulAssign<T> for Complex {
-    fn mul_assign(&mut self, rhs: T) {
-        let rhs = rhs.into();
-        let old_real = self.real;
-        let old_imag = self.imag;
-        
-        self.real = old_real * rhs.real - old_imag * rhs.imag;
-        self.imag = old_real * rhs.imag + old_imag * rhs.real;
+    if !ok {
+        std::process::exit(1);
     }
 }
-
-
-// --- CODUL DE TESTARE (Nemodificat) ---
-
-// This is a macro that panics if 2 floats are not equal using an epsilon.
-// You are not required to understand it yet, just to use it.
-macro_rules! assert_eq_rel {
-    ($x:expr, $y: expr) => {
-        let x = $x as f64;
-        let y = $y as f64;
-        let r = eq_rel(x, y);
-        assert!(r, "{} != {}", x, y);
-    };
-}
-
-fn main() {
-    let a = Complex::new(1.0, 2.0);
-    assert_eq_rel!(a.real, 1);
-    assert_eq_rel!(a.imag, 2);
-
-    let b = Complex::new(2.0, 3);
-    let c = a + b;
-    assert_eq_rel!(c.real, 3);
-    assert_eq_rel!(c.imag, 5);
-
-    let d = c - a;
-    assert_eq!(b, d);
-
-    let e = (a * d).conjugate();
-    assert_eq_rel!(e.imag, -7);
-
-    let f = (a + b - d) * c;
-    assert_eq!(f, Complex::new(-7, 11));
-
-    // Note: .to_string() uses Display to format the type
-    assert_eq!(Complex::new(1, 2).to_string(), "1+2i");
-    assert_eq!(Complex::new(1, -2).to_string(), "1-2i");
-    assert_eq!(Complex::new(0, 5).to_string(), "5i");
-    assert_eq!(Complex::new(7, 0).to_string(), "7");
-    assert_eq!(Complex::new(0, 0).to_string(), "0");
-
-    let h = Complex::new(-4, -5);
-    let i = h - (h + 5) * 2.0;
-    assert_eq_rel!(i.real, -6);
-
-    let j = -i + i;
-    assert_eq_rel!(j.real, 0);
-    assert_eq_rel!(j.imag, 0);
-
-    println!("ok!");
-}
\ No newline at end of file
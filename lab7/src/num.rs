@@ -0,0 +1,226 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// --- Functia de comparare (globala) ---
+// Functie helper pentru a compara f64 cu o marja de eroare
+pub fn eq_rel(x: f64, y: f64) -> bool {
+    (x - y).abs() < 0.001
+}
+
+// --- Toleranta explicita pentru comparatii aproximative ---
+
+/// Marja de eroare folosita de `Num::approx_eq_tol` si `Complex::approx_eq`.
+///
+/// Valorile egale exact (inclusiv infinitele de acelasi semn) sunt mereu egale;
+/// NaN nu este egal cu nimic.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Tolerance {
+    /// `|x - y| <= eps`
+    Absolute(f64),
+    /// `|x - y| <= eps * max(|x|, |y|)`
+    Relative(f64),
+    /// Cel mult `n` valori reprezentabile intre `x` si `y` (pentru intregi, `|x - y| <= n`)
+    Ulps(u64),
+}
+
+impl Tolerance {
+    /// Toleranta folosita de `PartialEq for Complex`, aceeasi marja ca `eq_rel`.
+    /// Fiind absoluta, nu are sens la scari mici sau mari; vezi `Tolerance::Relative`.
+    pub const DEFAULT: Tolerance = Tolerance::Absolute(0.001);
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::DEFAULT
+    }
+}
+
+// --- Trait-ul numeric peste care este generic `Complex<T>` ---
+
+/// Operatiile minime cerute de la tipul componentelor unui `Complex<T>`.
+///
+/// Este implementat pentru `f32`, `f64` si pentru intregii cu semn `i32`, `i64`
+/// (intregi Gaussieni).
+pub trait Num:
+    Copy
+    + Debug
+    + Display
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Valoarea absoluta a componentei
+    fn abs(self) -> Self;
+
+    /// Egalitate aproximativa cu toleranta explicita
+    fn approx_eq_tol(self, other: Self, tol: Tolerance) -> bool;
+
+    /// Egalitate stricta pe biti (`NaN` cu acelasi payload este egal, `0.0 != -0.0`)
+    fn bitwise_eq(self, other: Self) -> bool;
+
+    /// Egalitate aproximativa cu `Tolerance::DEFAULT`
+    fn approx_eq(self, other: Self) -> bool {
+        self.approx_eq_tol(other, Tolerance::DEFAULT)
+    }
+
+    /// Catul `(a+bi)/(c+di)` pe componente: `((ac+bd) + (bc-ad)i) / (c^2+d^2)`.
+    ///
+    /// Implementarea implicita este formula exacta, folosita de intregi.
+    fn complex_div((a, b): (Self, Self), (c, d): (Self, Self)) -> (Self, Self) {
+        let norm = c * c + d * d;
+        ((a * c + b * d) / norm, (b * c - a * d) / norm)
+    }
+}
+
+macro_rules! impl_num_float {
+    ($($t:ty),*) => {
+        $(
+            impl Num for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn approx_eq_tol(self, other: Self, tol: Tolerance) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() {
+                        return false;
+                    }
+                    let diff = (self - other).abs() as f64;
+                    match tol {
+                        Tolerance::Absolute(eps) => diff <= eps,
+                        Tolerance::Relative(eps) => {
+                            diff <= eps * (self.abs().max(other.abs()) as f64)
+                        }
+                        Tolerance::Ulps(max) => {
+                            // Semne diferite (si nu ambele zero): prea departe
+                            if self.is_sign_negative() != other.is_sign_negative() {
+                                return false;
+                            }
+                            let a = self.to_bits() as i128;
+                            let b = other.to_bits() as i128;
+                            (a - b).unsigned_abs() <= max as u128
+                        }
+                    }
+                }
+
+                fn bitwise_eq(self, other: Self) -> bool {
+                    self.to_bits() == other.to_bits()
+                }
+
+                fn complex_div((a, b): (Self, Self), (c, d): (Self, Self)) -> (Self, Self) {
+                    // Algoritmul lui Smith: se imparte la componenta mai mare a impartitorului,
+                    // ca `c^2+d^2` sa nu depaseasca pentru `|c+di|` mare (peste ~1e154 la `f64`)
+                    if c.abs() >= d.abs() {
+                        let r = d / c;
+                        let den = c + d * r;
+                        ((a + b * r) / den, (b - a * r) / den)
+                    } else {
+                        let r = c / d;
+                        let den = c * r + d;
+                        ((a * r + b) / den, (b * r - a) / den)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_num_int {
+    ($($t:ty),*) => {
+        $(
+            impl Num for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn approx_eq_tol(self, other: Self, tol: Tolerance) -> bool {
+                    let diff = self.abs_diff(other);
+                    match tol {
+                        Tolerance::Absolute(eps) => diff as f64 <= eps,
+                        Tolerance::Relative(eps) => {
+                            let largest = self.unsigned_abs().max(other.unsigned_abs());
+                            diff as f64 <= eps * largest as f64
+                        }
+                        Tolerance::Ulps(max) => diff as u64 <= max,
+                    }
+                }
+
+                fn bitwise_eq(self, other: Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_num_float!(f32, f64);
+impl_num_int!(i32, i64);
+
+// --- Trait-ul pentru componente in virgula mobila ---
+
+/// Functiile reale necesare pentru forma polara si functiile transcendente pe `Complex<T>`.
+///
+/// Este implementat doar pentru `f32` si `f64`.
+pub trait Float: Num {
+    const PI: Self;
+    /// Distanta dintre `1` si urmatoarea valoare reprezentabila
+    const EPSILON: Self;
+
+    /// Conversie (eventual cu pierdere de precizie) dintr-o constanta `f64`
+    fn from_f64(x: f64) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                const PI: Self = std::$t::consts::PI;
+                const EPSILON: Self = <$t>::EPSILON;
+
+                fn from_f64(x: f64) -> Self { x as $t }
+                fn sqrt(self) -> Self { <$t>::sqrt(self) }
+                fn exp(self) -> Self { <$t>::exp(self) }
+                fn ln(self) -> Self { <$t>::ln(self) }
+                fn sin(self) -> Self { <$t>::sin(self) }
+                fn cos(self) -> Self { <$t>::cos(self) }
+                fn sinh(self) -> Self { <$t>::sinh(self) }
+                fn cosh(self) -> Self { <$t>::cosh(self) }
+                fn tanh(self) -> Self { <$t>::tanh(self) }
+                fn atan2(self, other: Self) -> Self { <$t>::atan2(self, other) }
+                fn hypot(self, other: Self) -> Self { <$t>::hypot(self, other) }
+                fn copysign(self, sign: Self) -> Self { <$t>::copysign(self, sign) }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
@@ -0,0 +1,189 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use crate::complex::Complex;
+use crate::num::Num;
+
+// --- Eroarea de parsare ---
+
+/// Motivul pentru care un sir nu a putut fi interpretat ca `Complex<T>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseComplexErrorKind {
+    /// Sirul este gol (sau contine doar spatii)
+    Empty,
+    /// Partea reala nu este un numar valid
+    InvalidReal(String),
+    /// Coeficientul partii imaginare nu este un numar valid
+    InvalidImag(String),
+    /// Al doilea termen nu se termina cu `i` sau `j` (ex: `1+2`)
+    MissingImagUnit(String),
+}
+
+/// Eroarea intoarsa de `Complex::from_str` / `Complex::try_from(&str)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError {
+    input: String,
+    kind: ParseComplexErrorKind,
+}
+
+impl ParseComplexError {
+    fn new(input: &str, kind: ParseComplexErrorKind) -> Self {
+        ParseComplexError {
+            input: input.to_string(),
+            kind,
+        }
+    }
+
+    /// Sirul original care nu a putut fi parsat
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn kind(&self) -> &ParseComplexErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseComplexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "invalid complex number '{}': ", self.input)?;
+        match &self.kind {
+            ParseComplexErrorKind::Empty => write!(f, "empty string"),
+            ParseComplexErrorKind::InvalidReal(part) => write!(f, "invalid real part '{}'", part),
+            ParseComplexErrorKind::InvalidImag(part) => {
+                write!(f, "invalid imaginary part '{}'", part)
+            }
+            ParseComplexErrorKind::MissingImagUnit(part) => {
+                write!(f, "term '{}' is missing the imaginary unit 'i' or 'j'", part)
+            }
+        }
+    }
+}
+
+impl Error for ParseComplexError {}
+
+// --- Parsarea ---
+
+/// Cauta semnul care separa partea reala de cea imaginara: ultimul `+`/`-`
+/// care nu este la inceput si nu face parte dintr-un exponent (`1e-3`).
+fn split_point(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    (1..bytes.len()).rev().find(|&idx| {
+        matches!(bytes[idx], b'+' | b'-') && !matches!(bytes[idx - 1], b'e' | b'E')
+    })
+}
+
+/// Coeficientul din fata lui `i`: gol sau doar semn inseamna `1` / `-1`.
+/// Spatiile dintre semnul separator si numar sunt ignorate (`1 - 2i`).
+fn parse_imag<T: Num + FromStr>(coef: &str, separated: bool) -> Option<T> {
+    let coef = match coef.strip_prefix(['+', '-']) {
+        Some(rest) if separated => format!("{}{}", &coef[..1], rest.trim_start()),
+        _ => coef.to_string(),
+    };
+    match coef.as_str() {
+        "" | "+" => Some(T::ONE),
+        "-" => Some(-T::ONE),
+        _ => coef.parse().ok(),
+    }
+}
+
+/// Scoate unitatea `i`/`j` si spatiile dinaintea ei (`2 i`)
+fn strip_imag_unit(term: &str) -> Option<&str> {
+    term.strip_suffix('i').or_else(|| term.strip_suffix('j')).map(str::trim_end)
+}
+
+/// Accepta exact formele produse de `Display` (`1+2i`, `1-2i`, `5i`, `7`, `0`),
+/// plus notatie stiintifica (`1e-3+2.5E2i`), sufixul `j`, coeficient implicit
+/// (`i`, `-j`, `1+i`) si spatii la capete, in jurul semnului dintre parti si
+/// inainte de `i` (`1 + 2 i`). Un spatiu in interiorul unui numar (`1 2`) este o eroare.
+impl<T: Num + FromStr> FromStr for Complex<T> {
+    type Err = ParseComplexError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let s = input.trim();
+        if s.is_empty() {
+            return Err(ParseComplexError::new(input, ParseComplexErrorKind::Empty));
+        }
+
+        let (real_part, imag_part) = match split_point(s) {
+            Some(idx) => (Some(s[..idx].trim_end()), Some(&s[idx..])),
+            None if strip_imag_unit(s).is_some() => (None, Some(s)),
+            None => (Some(s), None),
+        };
+
+        let real = match real_part {
+            Some(part) => part.parse().map_err(|_| {
+                ParseComplexError::new(input, ParseComplexErrorKind::InvalidReal(part.to_string()))
+            })?,
+            None => T::ZERO,
+        };
+
+        let imag = match imag_part {
+            Some(part) => {
+                let coef = strip_imag_unit(part).ok_or_else(|| {
+                    ParseComplexError::new(
+                        input,
+                        ParseComplexErrorKind::MissingImagUnit(part.to_string()),
+                    )
+                })?;
+                parse_imag(coef, real_part.is_some()).ok_or_else(|| {
+                    ParseComplexError::new(input, ParseComplexErrorKind::InvalidImag(part.to_string()))
+                })?
+            }
+            None => T::ZERO,
+        };
+
+        Ok(Complex { real, imag })
+    }
+}
+
+impl<T: Num + FromStr> TryFrom<&str> for Complex<T> {
+    type Error = ParseComplexError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_display() {
+        for text in ["1+2i", "1-2i", "5i", "7", "0", "-0.5-1.25i"] {
+            let z: Complex = text.parse().unwrap();
+            assert_eq!(z.to_string(), text);
+        }
+        assert_eq!("3-4i".parse::<Complex<i32>>(), Ok(Complex::from_parts(3, -4)));
+    }
+
+    #[test]
+    fn accepts_exponents_j_and_implicit_coefficients() {
+        assert_eq!("1e-3 + 2.5E2 j".parse::<Complex>(), Ok(Complex::new(0.001, 250)));
+        assert_eq!(Complex::try_from("-i"), Ok(Complex::new(0, -1)));
+        assert_eq!("  -1 -  i ".parse::<Complex>(), Ok(Complex::new(-1, -1)));
+    }
+
+    #[test]
+    fn reports_invalid_input() {
+        let err = "1+2".parse::<Complex>().unwrap_err();
+        assert_eq!(err.kind(), &ParseComplexErrorKind::MissingImagUnit("+2".to_string()));
+        assert_eq!(
+            err.to_string(),
+            "invalid complex number '1+2': term '+2' is missing the imaginary unit 'i' or 'j'"
+        );
+        assert_eq!("".parse::<Complex>().unwrap_err().kind(), &ParseComplexErrorKind::Empty);
+        assert!("1+xi".parse::<Complex>().is_err());
+    }
+
+    #[test]
+    fn whitespace_only_around_the_separator() {
+        let err = "1 2".parse::<Complex>().unwrap_err();
+        assert_eq!(err.kind(), &ParseComplexErrorKind::InvalidReal("1 2".to_string()));
+        assert!("1 . 5 i".parse::<Complex>().is_err());
+        assert!("1 + 2 . 5i".parse::<Complex>().is_err());
+        assert!("- 5i".parse::<Complex>().is_err());
+    }
+}
@@ -0,0 +1,344 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::complex::{Complex, DivisionByZero};
+use crate::num::{Float, Num};
+
+// --- Definirea Structurii Polynomial ---
+
+/// Polinom cu coeficienti complecsi, stocati in ordinea crescatoare a puterilor:
+/// `coeffs[k]` este coeficientul lui `x^k`.
+///
+/// Coeficientii de rang maxim egali cu zero sunt eliminati, deci polinomul nul
+/// are `coeffs` gol si gradul `None`.
+#[derive(Debug, Clone)]
+pub struct Polynomial<T = f64> {
+    coeffs: Vec<Complex<T>>,
+}
+
+impl<T: Num> Polynomial<T> {
+    /// Creeaza polinomul din coeficienti, de la termenul liber la cel dominant
+    pub fn new<C: Into<Complex<T>>>(coeffs: Vec<C>) -> Self {
+        let mut p = Polynomial {
+            coeffs: coeffs.into_iter().map(Into::into).collect(),
+        };
+        p.trim();
+        p
+    }
+
+    /// Polinomul monic cu radacinile date: `(x - r1)(x - r2)...`
+    pub fn from_roots(roots: &[Complex<T>]) -> Self {
+        roots.iter().fold(Polynomial::new(vec![T::ONE]), |acc, &root| {
+            acc * Polynomial::new(vec![-root, Complex::from(T::ONE)])
+        })
+    }
+
+    /// Coeficientii, de la termenul liber la cel dominant
+    pub fn coeffs(&self) -> &[Complex<T>] {
+        &self.coeffs
+    }
+
+    /// Gradul polinomului; `None` pentru polinomul nul
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Evalueaza polinomul in `x` prin schema lui Horner
+    pub fn eval<X: Into<Complex<T>>>(&self, x: X) -> Complex<T> {
+        let x = x.into();
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Complex::from(T::ZERO), |acc, &c| acc * x + c)
+    }
+
+    /// Derivata formala
+    pub fn derivative(&self) -> Self {
+        let mut k = T::ZERO;
+        let coeffs = self
+            .coeffs
+            .iter()
+            .skip(1)
+            .map(|&c| {
+                k += T::ONE;
+                c * k
+            })
+            .collect();
+        Polynomial::new::<Complex<T>>(coeffs)
+    }
+
+    /// Impartirea cu rest: intoarce `(cat, rest)` astfel incat
+    /// `self = cat * divisor + rest` si `grad(rest) < grad(divisor)`.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), DivisionByZero> {
+        let lead = *divisor.coeffs.last().ok_or(DivisionByZero)?;
+        let divisor_len = divisor.coeffs.len();
+
+        let mut rem = self.coeffs.clone();
+        if rem.len() < divisor_len {
+            return Ok((Polynomial { coeffs: Vec::new() }, self.clone()));
+        }
+
+        let mut quot = vec![Complex::from(T::ZERO); rem.len() - divisor_len + 1];
+        for k in (0..quot.len()).rev() {
+            let factor = rem[k + divisor_len - 1] / lead;
+            quot[k] = factor;
+            for (j, &d) in divisor.coeffs.iter().enumerate() {
+                rem[k + j] -= factor * d;
+            }
+        }
+        rem.truncate(divisor_len - 1);
+
+        Ok((Polynomial::new::<Complex<T>>(quot), Polynomial::new::<Complex<T>>(rem)))
+    }
+
+    /// Elimina coeficientii dominanti nuli
+    fn trim(&mut self) {
+        while self.coeffs.last().is_some_and(Complex::is_zero) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+// --- Radacini (doar float-uri) ---
+
+/// Numarul maxim de iteratii Durand–Kerner inainte de a renunta
+const MAX_ITERATIONS: usize = 500;
+
+impl<T: Float> Polynomial<T> {
+    /// Toate radacinile complexe (cu multiplicitate), prin metoda Durand–Kerner.
+    ///
+    /// Iteratia se opreste cand nicio aproximare nu se mai misca cu mai mult de
+    /// `epsilon` sau dupa `MAX_ITERATIONS` pasi. Radacinile multiple converg
+    /// mai lent si cu precizie mai mica. Polinomul nul sau constant nu are radacini.
+    pub fn roots(&self, epsilon: T) -> Vec<Complex<T>> {
+        let degree = match self.degree() {
+            Some(d) if d > 0 => d,
+            _ => return Vec::new(),
+        };
+
+        // Lucram cu polinomul monic
+        let lead = self.coeffs[degree];
+        let monic: Vec<Complex<T>> = self.coeffs.iter().map(|&c| c / lead).collect();
+        let monic = Polynomial { coeffs: monic };
+
+        // Aproximarile initiale: puterile lui 0.4+0.9i (nu e radacina a unitatii),
+        // scalate la marginea Cauchy a radacinilor
+        let bound = monic.coeffs[..degree]
+            .iter()
+            .map(Complex::abs)
+            .fold(T::ZERO, |acc, a| if a > acc { a } else { acc })
+            + T::ONE;
+        let seed = Complex::from_parts(T::from_f64(0.4), T::from_f64(0.9));
+        let mut roots: Vec<Complex<T>> =
+            (0..degree).map(|k| seed.powi(k as i32) * bound).collect();
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut max_step = T::ZERO;
+            for i in 0..degree {
+                let mut denom = Complex::from(T::ONE);
+                for (j, &other) in roots.iter().enumerate() {
+                    if i != j {
+                        denom *= roots[i] - other;
+                    }
+                }
+                let step = monic.eval(roots[i]) / denom;
+                roots[i] -= step;
+                let step_size = step.abs();
+                if step_size > max_step {
+                    max_step = step_size;
+                }
+            }
+            if max_step <= epsilon {
+                break;
+            }
+        }
+        roots
+    }
+}
+
+// --- Implementarea PartialEq (coeficient cu coeficient, cu toleranta lui Complex) ---
+
+impl<T: Num> PartialEq for Polynomial<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+// --- Operatori ---
+
+impl<T: Num> Add for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self.coeffs, rhs.coeffs)
+        } else {
+            (rhs.coeffs, self.coeffs)
+        };
+        for (a, b) in long.iter_mut().zip(short) {
+            *a += b;
+        }
+        Polynomial::new::<Complex<T>>(long)
+    }
+}
+
+impl<T: Num> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn neg(self) -> Self::Output {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<T: Num> Sub for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<T: Num> Mul for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial { coeffs: Vec::new() };
+        }
+        let mut coeffs = vec![Complex::from(T::ZERO); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] += a * b;
+            }
+        }
+        Polynomial::new::<Complex<T>>(coeffs)
+    }
+}
+
+// --- Implementarea `Display` ---
+
+impl<T: Num> Display for Polynomial<T> {
+    /// Afiseaza de la termenul dominant, ex: `(1+2i)x^2 + 3x + 5i`
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (power, c) in self.coeffs.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            // Un coeficient negativ cu o singura componenta se scrie `- 3x`, nu `+ -3x`
+            let needs_parens = c.real != T::ZERO && c.imag != T::ZERO;
+            let negative = !needs_parens && (c.real < T::ZERO || c.imag < T::ZERO);
+            let c = if negative { -*c } else { *c };
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+
+            // Comparatie exacta: un coeficient ca 1.0005 nu trebuie ascuns
+            let is_one = c.real == T::ONE && c.imag == T::ZERO;
+            match (power, needs_parens) {
+                (0, _) => write!(f, "{}", c)?,
+                (_, true) => write!(f, "({})", c)?,
+                (_, false) if is_one => {}
+                (_, false) => write!(f, "{}", c)?,
+            }
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", power)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-12;
+
+    /// Verifica ca fiecare radacina asteptata apare in `found` (ordinea nu conteaza)
+    fn assert_same_roots(mut found: Vec<Complex>, expected: &[Complex]) {
+        assert_eq!(found.len(), expected.len());
+        for root in expected {
+            let idx = found
+                .iter()
+                .position(|r| r == root)
+                .unwrap_or_else(|| panic!("root {} not found in {:?}", root, found));
+            found.remove(idx);
+        }
+    }
+
+    #[test]
+    fn horner_evaluation() {
+        // x^2 + 1
+        let p = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        assert_eq!(p.eval(Complex::new(0, 1)), Complex::from(0.0));
+        assert_eq!(p.eval(2), Complex::from(5.0));
+        assert_eq!(p.degree(), Some(2));
+    }
+
+    #[test]
+    fn derivative_and_trimming() {
+        let p = Polynomial::new(vec![5.0, 3.0, 2.0, 0.0]);
+        assert_eq!(p.degree(), Some(2));
+        assert_eq!(p.derivative(), Polynomial::new(vec![3.0, 4.0]));
+        assert!(Polynomial::<f64>::new(vec![7.0]).derivative().is_zero());
+    }
+
+    #[test]
+    fn multiplication_and_division() {
+        let a = Polynomial::new(vec![Complex::new(1, 1), Complex::new(2, 0)]);
+        let b = Polynomial::new(vec![Complex::new(-3, 0), Complex::new(0, 1), Complex::new(1, 0)]);
+        let r = Polynomial::new(vec![Complex::new(0, 5)]);
+        let product = a.clone() * b.clone() + r.clone();
+
+        let (q, rem) = product.div_rem(&b).unwrap();
+        assert_eq!(q, a);
+        assert_eq!(rem, r);
+        assert_eq!(b.div_rem(&Polynomial::new(Vec::<f64>::new())), Err(DivisionByZero));
+    }
+
+    #[test]
+    fn roots_of_unity() {
+        // x^4 - 1
+        let p = Polynomial::new(vec![-1.0, 0.0, 0.0, 0.0, 1.0]);
+        let expected = [Complex::new(1, 0), Complex::new(-1, 0), Complex::new(0, 1), Complex::new(0, -1)];
+        assert_same_roots(p.roots(EPS), &expected);
+    }
+
+    #[test]
+    fn roots_round_trip_through_from_roots() {
+        let expected = [Complex::new(2, -1), Complex::new(-0.5, 3), Complex::new(0, 0), Complex::new(4, 0)];
+        let p = Polynomial::from_roots(&expected) * Polynomial::new(vec![Complex::new(0, 3)]);
+        assert_same_roots(p.roots(EPS), &expected);
+    }
+
+    #[test]
+    fn characteristic_equation_with_double_root() {
+        // x^2 + 2x + 1 = (x + 1)^2
+        let p = Polynomial::new(vec![1.0, 2.0, 1.0]);
+        assert_same_roots(p.roots(EPS), &[Complex::new(-1, 0), Complex::new(-1, 0)]);
+    }
+
+    #[test]
+    fn display() {
+        let p = Polynomial::new(vec![Complex::new(0, 5), Complex::new(3, 0), Complex::new(1, 2)]);
+        assert_eq!(p.to_string(), "(1+2i)x^2 + 3x + 5i");
+        assert_eq!(Polynomial::new(vec![0.0, 1.0]).to_string(), "x");
+        assert_eq!(Polynomial::new(vec![0.0, -3.0, 1.0005]).to_string(), "1.0005x^2 - 3x");
+        assert_eq!(Polynomial::new(vec![-5.0, 0.0, -1.0]).to_string(), "-x^2 - 5");
+        let p = Polynomial::new(vec![Complex::new(0, -2), Complex::new(1, -1), Complex::new(0, 1)]);
+        assert_eq!(p.to_string(), "1ix^2 + (1-1i)x - 2i");
+        assert_eq!(Polynomial::<f64>::new(Vec::<f64>::new()).to_string(), "0");
+    }
+}
@@ -0,0 +1,296 @@
+use crate::algebra::{impl_algebra, Algebra};
+use crate::complex::Complex;
+use crate::num::{Float, Num};
+
+// --- Definirea Structurii Quaternion ---
+
+/// Cuaternion `w + xi + yj + zk` (implicit peste `f64`).
+///
+/// Inmultirea este produsul Hamilton (necomutativ); `a / b` inseamna `a * b^-1`.
+#[derive(Debug, Copy, Clone)]
+pub struct Quaternion<T = f64> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Vector 3D folosit pentru axe si rotatii
+pub type Vec3<T> = [T; 3];
+
+/// Matrice de rotatie 3x3, pe linii
+pub type Mat3<T> = [[T; 3]; 3];
+
+impl<T: Num> Quaternion<T> {
+    pub fn from_parts(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// Cuaternionul pur `0 + v`
+    pub fn from_vector(v: Vec3<T>) -> Self {
+        Quaternion::from_parts(T::ZERO, v[0], v[1], v[2])
+    }
+
+    /// Partea vectoriala `(x, y, z)`
+    pub fn vector(&self) -> Vec3<T> {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Quaternion::from_parts(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn norm_sqr(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Produsul scalar al celor 4 componente
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Inversul `conj(q) / |q|^2`
+    pub fn recip(&self) -> Self {
+        let norm = self.norm_sqr();
+        let c = self.conjugate();
+        Quaternion::from_parts(c.w / norm, c.x / norm, c.y / norm, c.z / norm)
+    }
+}
+
+impl<T: Num> Algebra for Quaternion<T> {
+    type Scalar = T;
+
+    fn product(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Quaternion {
+            w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        }
+    }
+
+    fn quotient(self, rhs: Self) -> Self {
+        self.product(rhs.recip())
+    }
+}
+
+impl_algebra!(Quaternion { w: "", x: "i", y: "j", z: "k" });
+
+/// Un numar complex `a+bi` este cuaternionul `a + bi + 0j + 0k`
+impl<T: Num> From<Complex<T>> for Quaternion<T> {
+    fn from(c: Complex<T>) -> Self {
+        Quaternion::from_parts(c.real, c.imag, T::ZERO, T::ZERO)
+    }
+}
+
+// --- Rotatii (doar float-uri) ---
+
+impl<T: Float> Quaternion<T> {
+    pub fn norm(&self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    /// Cuaternionul unitate cu aceeasi directie
+    pub fn normalize(&self) -> Self {
+        *self / self.norm()
+    }
+
+    /// Rotatia cu unghiul `angle` (radiani) in jurul axei `axis` (nu trebuie sa fie unitara).
+    /// O axa nula nu defineste nicio rotatie: rezultatul este rotatia identica `1`.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Self {
+        let len = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        if len == T::ZERO {
+            return Quaternion::from(T::ONE);
+        }
+        let half = angle / (T::ONE + T::ONE);
+        let s = half.sin() / len;
+        Quaternion::from_parts(half.cos(), axis[0] * s, axis[1] * s, axis[2] * s)
+    }
+
+    /// Inversul lui `from_axis_angle` pentru un cuaternion unitate; unghiul este in `[0, 2π]`.
+    /// Pentru rotatia identica axa intoarsa este `(1, 0, 0)`.
+    pub fn to_axis_angle(&self) -> (Vec3<T>, T) {
+        let q = self.normalize();
+        let len = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        let angle = (T::ONE + T::ONE) * len.atan2(q.w);
+        if len == T::ZERO {
+            return ([T::ONE, T::ZERO, T::ZERO], angle);
+        }
+        ([q.x / len, q.y / len, q.z / len], angle)
+    }
+
+    /// Roteste vectorul `v` cu acest cuaternion unitate: `q v q^-1`
+    pub fn rotate_vector(&self, v: Vec3<T>) -> Vec3<T> {
+        (*self * Quaternion::from_vector(v) * self.conjugate()).vector()
+    }
+
+    /// Matricea de rotatie echivalenta (pentru un cuaternion unitate)
+    pub fn to_rotation_matrix(&self) -> Mat3<T> {
+        let Quaternion { w, x, y, z } = *self;
+        let one = T::ONE;
+        let two = one + one;
+        [
+            [one - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+            [two * (x * y + w * z), one - two * (x * x + z * z), two * (y * z - w * x)],
+            [two * (x * z - w * y), two * (y * z + w * x), one - two * (x * x + y * y)],
+        ]
+    }
+
+    /// Cuaternionul unitate pentru o matrice de rotatie (metoda lui Shepperd:
+    /// se porneste de la cea mai mare componenta, pentru stabilitate numerica)
+    pub fn from_rotation_matrix(m: &Mat3<T>) -> Self {
+        let one = T::ONE;
+        let two = one + one;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        let q = if trace > T::ZERO {
+            let s = (trace + one).sqrt() * two; // s = 4w
+            Quaternion::from_parts(
+                s / (two + two),
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two; // s = 4x
+            Quaternion::from_parts(
+                (m[2][1] - m[1][2]) / s,
+                s / (two + two),
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two; // s = 4y
+            Quaternion::from_parts(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / (two + two),
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two; // s = 4z
+            Quaternion::from_parts(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / (two + two),
+            )
+        };
+        q.normalize()
+    }
+
+    /// Interpolare sferica intre doua rotatii, `t` in `[0, 1]`, pe drumul cel mai scurt.
+    /// Pentru rotatii aproape identice cade pe interpolare liniara normalizata.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let a = self.normalize();
+        let mut b = other.normalize();
+        let mut cos = a.dot(&b);
+        if cos < T::ZERO {
+            b = -b;
+            cos = -cos;
+        }
+
+        if cos > T::from_f64(0.9995) {
+            return (a + (b - a) * t).normalize();
+        }
+
+        let sin = (T::ONE - cos * cos).sqrt();
+        let theta = sin.atan2(cos);
+        let wa = ((T::ONE - t) * theta).sin() / sin;
+        let wb = (t * theta).sin() / sin;
+        a * wa + b * wb
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_vec_eq(a: Vec3<f64>, b: Vec3<f64>) {
+        for k in 0..3 {
+            assert!((a[k] - b[k]).abs() < 1e-9, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn hamilton_product_rules() {
+        let i = Quaternion::from_parts(0.0, 1.0, 0.0, 0.0);
+        let j = Quaternion::from_parts(0.0, 0.0, 1.0, 0.0);
+        let k = Quaternion::from_parts(0.0, 0.0, 0.0, 1.0);
+        let minus_one = Quaternion::from(-1.0);
+        assert_eq!(i * i, minus_one);
+        assert_eq!(i * j * k, minus_one);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+
+        let q = Quaternion::from_parts(1.0, -2.0, 0.5, 3.0);
+        assert_eq!(q * q.recip(), Quaternion::from(1.0));
+        assert_eq!((q * j) / j, q);
+    }
+
+    #[test]
+    fn shares_operators_and_display_with_complex() {
+        let q: Quaternion = Quaternion::from(Complex::new(1, 2)) + 3.0;
+        assert_eq!(q, Quaternion::from_parts(4.0, 2.0, 0.0, 0.0));
+        assert_eq!(Quaternion::from_parts(1, -2, 0, 4).to_string(), "1-2i+4k");
+        assert_eq!(Quaternion::from_parts(0, 0, -3, 0).to_string(), "-3j");
+        assert_eq!(Quaternion::<i32>::from(0).to_string(), "0");
+    }
+
+    #[test]
+    fn axis_angle_round_trip_and_rotation() {
+        let q = Quaternion::from_axis_angle([0.0, 0.0, 2.0], FRAC_PI_2);
+        assert_vec_eq(q.rotate_vector([1.0, 0.0, 0.0]), [0.0, 1.0, 0.0]);
+
+        let (axis, angle) = q.to_axis_angle();
+        assert_vec_eq(axis, [0.0, 0.0, 1.0]);
+        assert!((angle - FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn zero_axis_is_identity() {
+        for angle in [0.0, FRAC_PI_2] {
+            let q = Quaternion::from_axis_angle([0.0, 0.0, 0.0], angle);
+            assert!(q.w == 1.0 && q.x == 0.0 && q.y == 0.0 && q.z == 0.0, "{:?}", q);
+            assert_vec_eq(q.rotate_vector([1.0, 2.0, 3.0]), [1.0, 2.0, 3.0]);
+        }
+    }
+
+    #[test]
+    fn rotation_matrix_round_trip() {
+        for q in [
+            Quaternion::from_axis_angle([1.0, 2.0, 3.0], 0.7),
+            Quaternion::from_axis_angle([1.0, 0.0, 0.0], PI),
+            Quaternion::from_axis_angle([0.0, 1.0, 0.0], 3.0),
+            Quaternion::from_axis_angle([0.0, 0.0, 1.0], -3.0),
+        ] {
+            let m = q.to_rotation_matrix();
+            let v = [0.3, -1.0, 2.0];
+            let rotated = [
+                m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+                m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+                m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+            ];
+            assert_vec_eq(rotated, q.rotate_vector(v));
+
+            // q si -q reprezinta aceeasi rotatie
+            let back = Quaternion::from_rotation_matrix(&m);
+            assert!(back == q || back == -q, "{} vs {}", back, q);
+        }
+    }
+
+    #[test]
+    fn slerp_interpolates_the_angle() {
+        let axis = [0.0, 1.0, 0.0];
+        let a = Quaternion::from_axis_angle(axis, 0.2);
+        let b = Quaternion::from_axis_angle(axis, 1.4);
+        assert_eq!(a.slerp(&b, 0.0), a);
+        assert_eq!(a.slerp(&b, 1.0), b);
+        assert_eq!(a.slerp(&b, 0.25), Quaternion::from_axis_angle(axis, 0.5));
+        // drumul cel mai scurt: -b este aceeasi rotatie
+        assert_eq!(a.slerp(&-b, 0.5), Quaternion::from_axis_angle(axis, 0.8));
+        assert_eq!(a.slerp(&a, 0.5), a);
+    }
+}
@@ -0,0 +1,102 @@
+// Forme alternative de serializare pentru `Complex<T>`, selectate cu atributul
+// `#[serde(with = "...")]` pe campul care contine numarul:
+//
+//     #[derive(Serialize, Deserialize)]
+//     struct Sample {
+//         z: Complex,                                     // {"real":1.0,"imag":2.0}
+//         #[serde(with = "lab7::serialization::compact")]
+//         c: Complex,                                     // [1.0,2.0]
+//         #[serde(with = "lab7::serialization::string")]
+//         s: Complex,                                     // "1+2i"
+//     }
+
+/// `Complex` ca pereche `[real, imag]`
+pub mod compact {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::complex::Complex;
+
+    pub fn serialize<T, S>(z: &Complex<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        (&z.real, &z.imag).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Complex<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let (real, imag) = <(T, T)>::deserialize(deserializer)?;
+        Ok(Complex { real, imag })
+    }
+}
+
+/// `Complex` ca sir, in formatul lui `Display` (ex: `"1-2i"`), parsat inapoi cu `FromStr`
+pub mod string {
+    use std::str::FromStr;
+
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::complex::Complex;
+    use crate::num::Num;
+
+    pub fn serialize<T, S>(z: &Complex<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Num,
+        S: Serializer,
+    {
+        serializer.collect_str(z)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Complex<T>, D::Error>
+    where
+        T: Num + FromStr,
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::complex::Complex;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        z: Complex,
+        #[serde(with = "crate::serialization::compact")]
+        c: Complex<i32>,
+        #[serde(with = "crate::serialization::string")]
+        s: Complex,
+    }
+
+    #[test]
+    fn all_forms_round_trip_through_json() {
+        let sample = Sample {
+            z: Complex::new(1.5, -2),
+            c: Complex::from_parts(3, 4),
+            s: Complex::new(0.1, -7),
+        };
+        let json = serde_json::to_string(&sample).unwrap();
+        assert_eq!(json, r#"{"z":{"real":1.5,"imag":-2.0},"c":[3,4],"s":"0.1-7i"}"#);
+
+        let back: Sample = serde_json::from_str(&json).unwrap();
+        assert!(back.z.bitwise_eq(&sample.z));
+        assert!(back.s.bitwise_eq(&sample.s));
+        assert_eq!(back, sample);
+    }
+
+    #[test]
+    fn invalid_string_reports_parse_error() {
+        let err = serde_json::from_str::<Sample>(r#"{"z":{"real":0,"imag":0},"c":[0,0],"s":"1+2"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("missing the imaginary unit"), "{}", err);
+    }
+}
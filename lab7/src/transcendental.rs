@@ -0,0 +1,340 @@
+use std::fmt;
+
+use crate::algebra::{display_value, pad};
+use crate::complex::Complex;
+use crate::num::{Float, Num};
+
+// --- Puteri intregi (orice `T: Num`) ---
+
+impl<T: Num> Complex<T> {
+    /// Ridica numarul la puterea intreaga `n` prin ridicare repetata la patrat.
+    ///
+    /// Pentru `n < 0` rezultatul este `recip(z^|n|)`; `0^n` cu `n < 0` este `inf`, ca la
+    /// `powc` (la intregi panica, ca orice impartire la zero).
+    pub fn powi(&self, n: i32) -> Self {
+        if n < 0 && self.is_zero() {
+            return Complex::from(T::ONE / T::ZERO);
+        }
+        let mut base = *self;
+        let mut exp = n.unsigned_abs();
+        let mut result = Complex::from(T::ONE);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            exp >>= 1;
+            // Patratul de dupa ultimul bit nu se mai foloseste (si ar putea depasi la intregi)
+            if exp > 0 {
+                base *= base;
+            }
+        }
+
+        if n < 0 {
+            result.recip()
+        } else {
+            result
+        }
+    }
+}
+
+// --- Forma polara si functii transcendente (doar float-uri) ---
+//
+// Taieturi (branch cuts): `ln`, `sqrt` si `powc` folosesc ramura principala,
+// cu taietura de-a lungul semiaxei reale negative. Pe taietura, semnul lui zero
+// din `imag` decide partea (ca in C99): `ln(-1+0i) = iπ`, `ln(-1-0i) = -iπ`.
+
+impl<T: Float> Complex<T> {
+    /// Creeaza un numar complex din modulul `r` si argumentul `theta` (radiani)
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Complex {
+            real: r * theta.cos(),
+            imag: r * theta.sin(),
+        }
+    }
+
+    /// Intoarce `(modul, argument)`; inversul lui `from_polar`
+    pub fn to_polar(&self) -> (T, T) {
+        (self.abs(), self.arg())
+    }
+
+    /// Afisare in forma polara `r∠θ` (radiani; cu `{:#}` in grade: `r∠θ°`)
+    pub fn display_polar(&self) -> PolarDisplay<T> {
+        PolarDisplay(*self)
+    }
+
+    /// Modulul `|z|`, calculat cu `hypot` pentru a evita depasirea
+    pub fn abs(&self) -> T {
+        self.real.hypot(self.imag)
+    }
+
+    /// Argumentul principal, in intervalul `[-π, π]`
+    pub fn arg(&self) -> T {
+        self.imag.atan2(self.real)
+    }
+
+    /// `e^z = e^a (cos b + i sin b)`
+    pub fn exp(&self) -> Self {
+        Complex::from_polar(self.real.exp(), self.imag)
+    }
+
+    /// Logaritmul natural principal: `ln|z| + i arg(z)`, cu `imag` in `[-π, π]`.
+    ///
+    /// Taietura este semiaxa reala negativa; `ln(0)` este `-inf + 0i`.
+    pub fn ln(&self) -> Self {
+        Complex {
+            real: self.abs().ln(),
+            imag: self.arg(),
+        }
+    }
+
+    /// Radacina patrata principala, cu `real >= 0`.
+    ///
+    /// Taietura este semiaxa reala negativa; `imag` pastreaza semnul lui `self.imag`.
+    pub fn sqrt(&self) -> Self {
+        if self.is_zero() {
+            return Complex::from(T::ZERO);
+        }
+        // Componenta mare din `sqrt((|z| + |a|) / 2)`, cea mica din `b / 2t`: scaderea
+        // `|z| - |a|` ar pierde toata precizia cand `b` este mic fata de `a`
+        let two = T::ONE + T::ONE;
+        let (a, b) = (self.real, self.imag);
+        let t = ((self.abs() + a.abs()) / two).sqrt();
+        if a >= T::ZERO {
+            Complex { real: t, imag: b / (two * t) }
+        } else {
+            Complex {
+                real: b.abs() / (two * t),
+                imag: t.copysign(b),
+            }
+        }
+    }
+
+    /// `z^w = e^(w ln z)`, pe ramura principala a lui `ln`.
+    ///
+    /// Pentru baza zero: `0^0 = 1` (prin conventie), `0^w = 0` daca `Re(w) > 0`,
+    /// `inf` daca `Re(w) < 0` si NaN daca `Re(w) = 0` (cu `Im(w) != 0`).
+    pub fn powc(&self, w: Complex<T>) -> Self {
+        if self.is_zero() {
+            return if w.is_zero() {
+                Complex::from(T::ONE)
+            } else if w.real > T::ZERO {
+                Complex::from(T::ZERO)
+            } else if w.real < T::ZERO {
+                Complex::from(T::from_f64(f64::INFINITY))
+            } else {
+                Complex::from(T::from_f64(f64::NAN))
+            };
+        }
+        (w * self.ln()).exp()
+    }
+
+    /// `sin(a+bi) = sin a cosh b + i cos a sinh b`
+    pub fn sin(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        Complex {
+            real: a.sin() * b.cosh(),
+            imag: a.cos() * b.sinh(),
+        }
+    }
+
+    /// `cos(a+bi) = cos a cosh b - i sin a sinh b`
+    pub fn cos(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        Complex {
+            real: a.cos() * b.cosh(),
+            imag: -(a.sin() * b.sinh()),
+        }
+    }
+
+    /// `tan(a+bi) = (sin 2a + i sinh 2b) / (cos 2a + cosh 2b)`
+    pub fn tan(&self) -> Self {
+        // Numaratorul si numitorul impartite la `cosh 2b`, care depaseste pentru |b| mare:
+        // atunci `real -> 0` si `imag -> tanh 2b = ±1`, in loc de `inf/inf = NaN`
+        let (a, b) = (self.real + self.real, self.imag + self.imag);
+        let cosh = b.cosh();
+        let denom = T::ONE + a.cos() / cosh;
+        Complex {
+            real: a.sin() / cosh / denom,
+            imag: b.tanh() / denom,
+        }
+    }
+
+    /// `sinh(a+bi) = sinh a cos b + i cosh a sin b`
+    pub fn sinh(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        Complex {
+            real: a.sinh() * b.cos(),
+            imag: a.cosh() * b.sin(),
+        }
+    }
+
+    /// `cosh(a+bi) = cosh a cos b + i sinh a sin b`
+    pub fn cosh(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        Complex {
+            real: a.cosh() * b.cos(),
+            imag: a.sinh() * b.sin(),
+        }
+    }
+
+    /// `tanh(a+bi) = (sinh 2a + i sin 2b) / (cosh 2a + cos 2b)`
+    pub fn tanh(&self) -> Self {
+        // Ca la `tan`, impartit la `cosh 2a`, ca `tanh` sa tinda la ±1 pentru |a| mare
+        let (a, b) = (self.real + self.real, self.imag + self.imag);
+        let cosh = a.cosh();
+        let denom = T::ONE + b.cos() / cosh;
+        Complex {
+            real: a.tanh() / denom,
+            imag: b.sin() / cosh / denom,
+        }
+    }
+}
+
+/// Adaptor de afisare intors de `Complex::display_polar`.
+///
+/// Precizia se aplica atat modulului cat si unghiului; latimea si alinierea
+/// se aplica intregului text, ca la `Display` pentru `Complex`.
+#[derive(Debug, Copy, Clone)]
+pub struct PolarDisplay<T>(Complex<T>);
+
+impl<T: Float> fmt::Display for PolarDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (r, mut theta) = self.0.to_polar();
+        let unit = if f.alternate() {
+            theta = theta * T::from_f64(180.0) / T::PI;
+            "°"
+        } else {
+            ""
+        };
+        let precision = f.precision();
+        let mut text = String::new();
+        if f.sign_plus() {
+            text.push('+');
+        }
+        text += &display_value(r, precision);
+        text.push('∠');
+        text += &display_value(theta, precision);
+        text += unit;
+        pad(f, &text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num::{eq_rel, Tolerance};
+    use std::f64::consts::{E, FRAC_PI_2, PI};
+
+    fn i() -> Complex {
+        Complex::new(0, 1)
+    }
+
+    #[test]
+    fn euler_identity() {
+        assert_eq!((i() * PI).exp(), Complex::from(-1.0));
+        assert_eq!(Complex::from(1.0).exp(), Complex::from(E));
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        let z = Complex::new(-3, 4);
+        let (r, theta) = z.to_polar();
+        assert!(eq_rel(r, 5.0));
+        assert_eq!(Complex::from_polar(r, theta), z);
+        assert!(eq_rel(Complex::from(-1.0).arg(), PI));
+        assert!(eq_rel(i().arg(), FRAC_PI_2));
+    }
+
+    #[test]
+    fn polar_display() {
+        let z = Complex::new(-3, 4);
+        assert_eq!(format!("{:.3}", z.display_polar()), "5.000∠2.214");
+        assert_eq!(format!("{:#.1}", z.display_polar()), "5.0∠126.9°");
+        assert_eq!(format!("{:>10.0}|", i().display_polar()), "       1∠2|");
+        assert_eq!(format!("{:#}", Complex::from(2.0).display_polar()), "2∠0°");
+    }
+
+    #[test]
+    fn ln_is_inverse_of_exp() {
+        let z = Complex::new(0.5, -1.25);
+        assert_eq!(z.exp().ln(), z);
+        assert_eq!(z.ln().exp(), z);
+        assert_eq!(Complex::from(-1.0).ln(), i() * PI);
+    }
+
+    #[test]
+    fn branch_cut_follows_sign_of_zero() {
+        let above = Complex::new(-4.0, 0.0);
+        let below = Complex::new(-4.0, -0.0);
+        assert_eq!(above.sqrt(), Complex::new(0, 2));
+        assert_eq!(below.sqrt(), Complex::new(0, -2));
+        assert!(eq_rel(above.ln().imag, PI));
+        assert!(eq_rel(below.ln().imag, -PI));
+    }
+
+    #[test]
+    fn sqrt_squared_is_identity() {
+        for z in [Complex::new(3, 4), Complex::new(-2, 0.5), Complex::new(0, -9)] {
+            let s = z.sqrt();
+            assert!(s.real >= 0.0);
+            assert_eq!(s * s, z);
+        }
+    }
+
+    #[test]
+    fn sqrt_keeps_tiny_imaginary_parts() {
+        let tol = Tolerance::Relative(1e-12);
+        assert!(Complex::new(1, 1e-10).sqrt().approx_eq(&Complex::new(1, 5e-11), tol));
+        assert!(Complex::new(-1, 1e-10).sqrt().approx_eq(&Complex::new(5e-11, 1), tol));
+        assert!(Complex::new(-1, -1e-10).sqrt().approx_eq(&Complex::new(5e-11, -1), tol));
+        assert!(Complex::new(1e300, 1e300).sqrt().real.is_finite());
+    }
+
+    #[test]
+    fn powers() {
+        let z = Complex::new(1, 1);
+        assert_eq!(z.powi(4), Complex::from(-4.0));
+        assert_eq!(z.powi(-2), Complex::new(0, -0.5));
+        assert_eq!(z.powi(0), Complex::from(1.0));
+        assert_eq!(z.powc(Complex::from(3.0)), z * z * z);
+        // i^i = e^(-π/2)
+        assert_eq!(i().powc(i()), Complex::from((-FRAC_PI_2).exp()));
+        assert_eq!(Complex::from(0.0).powc(Complex::from(0.0)), Complex::from(1.0));
+        assert_eq!(Complex::from(0.0).powc(Complex::new(0.5, -2)), Complex::from(0.0));
+        assert_eq!(Complex::from(0.0).powc(Complex::from(-0.5)).real, f64::INFINITY);
+        assert!(Complex::from(0.0).powc(Complex::new(0, 1)).real.is_nan());
+        assert_eq!(Complex::from(0.0).powi(-2).real, f64::INFINITY);
+        assert_eq!(Complex::from(0.0).powi(-2).real, Complex::from(0.0).powc(Complex::from(-2.0)).real);
+        assert_eq!(Complex::from_parts(2, 1).powi(3), Complex::from_parts(2, 11));
+        assert_eq!(Complex::from_parts(50_000, 0).powi(1), Complex::from_parts(50_000, 0));
+        assert_eq!(Complex::from_parts(-1000, 0).powi(3), Complex::from_parts(-1_000_000_000, 0));
+    }
+
+    #[test]
+    fn trigonometric_identities() {
+        let z = Complex::new(0.7, -0.3);
+        let one = Complex::from(1.0);
+        assert_eq!(z.sin() * z.sin() + z.cos() * z.cos(), one);
+        assert_eq!(z.cosh() * z.cosh() - z.sinh() * z.sinh(), one);
+        assert_eq!(z.tan(), z.sin() / z.cos());
+        assert_eq!(z.tanh(), z.sinh() / z.cosh());
+        // sin(iz) = i sinh(z)
+        assert_eq!((i() * z).sin(), i() * z.sinh());
+    }
+
+    #[test]
+    fn tan_and_tanh_of_large_arguments() {
+        assert!(Complex::new(0, 400).tan().bitwise_eq(&Complex::new(0, 1)));
+        assert!(Complex::new(0.5, -400).tan().approx_eq(&Complex::new(0, -1), Tolerance::Ulps(1)));
+        assert!(Complex::from(400.0).tanh().bitwise_eq(&Complex::from(1.0)));
+        assert!(Complex::new(-400, 0.5).tanh().approx_eq(&Complex::new(-1, 0), Tolerance::Ulps(1)));
+    }
+
+    #[test]
+    fn works_for_f32() {
+        let z = Complex::<f32>::from_parts(0.0, std::f32::consts::PI);
+        assert_eq!(z.exp(), Complex::from(-1.0f32));
+        assert_eq!(Complex::<f32>::from(-9.0).sqrt(), Complex::from_parts(0.0, 3.0));
+    }
+}